@@ -1,63 +1,258 @@
-use k8s_openapi::api::apps::v1::Deployment;
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use sha2::{Digest, Sha256};
+
+use crate::diff::{
+    ImageChange, TemplateChanges, image_changes, parse_template, restarted_at, template_changes,
+};
 use crate::state::{DeploymentState, StateManager};
+use crate::workload::Workload;
+
+// 워크로드별로 멈춤 판단 시간을 덮어쓸 수 있는 annotation (초 단위)
+const STALL_TIMEOUT_ANNOTATION: &str = "bellboy/stall-timeout-seconds";
+
+// 롤백 판단을 위해 기억하는 Pod template 개수 (Deployment의 revisionHistoryLimit 기본값과 동일)
+const TEMPLATE_HISTORY_LIMIT: usize = 10;
 
 #[derive(Debug)]
 pub enum DeploymentEvent {
     DeploymentStarted {
+        kind: String,
         namespace: String,
         name: String,
         old_generation: i64,
         new_generation: i64,
         old_replicas: Option<i32>,
         new_replicas: Option<i32>,
+        image_changes: Vec<ImageChange>,
+        template_changes: TemplateChanges,
+    },
+    DeploymentRestarted {
+        kind: String,
+        namespace: String,
+        name: String,
+        old_generation: i64,
+        new_generation: i64,
+        restarted_at: String,
+    },
+    DeploymentRestartCompleted {
+        kind: String,
+        namespace: String,
+        name: String,
+        generation: i64,
+        replicas: i32,
+    },
+    DeploymentRolledBack {
+        kind: String,
+        namespace: String,
+        name: String,
+        old_generation: i64,
+        new_generation: i64,
+        rolled_back_to: i64, // 되돌아간 template이 처음 배포된 revision
     },
     DeploymentCompleted {
+        kind: String,
         namespace: String,
         name: String,
         generation: i64,
         replicas: i32,
         replica_changed: Option<(i32, i32)>, // (old, new)
+        image_changes: Vec<ImageChange>,
+        template_changes: TemplateChanges,
     },
     ReplicaScaleStarted {
+        kind: String,
         namespace: String,
         name: String,
         old_replicas: i32,
         new_replicas: i32,
     },
     ReplicaScaleCompleted {
+        kind: String,
+        namespace: String,
+        name: String,
+        replicas: i32,
+    },
+    DeploymentStalled {
+        kind: String,
         namespace: String,
         name: String,
+        generation: i64,
+        elapsed_seconds: u64,
         replicas: i32,
+        ready_replicas: i32,
+        updated_replicas: i32,
+    },
+    DeploymentFailed {
+        kind: String,
+        namespace: String,
+        name: String,
+        generation: i64,
+        reason: String,
+        message: String,
+    },
+    RolloutProgressed {
+        kind: String,
+        namespace: String,
+        name: String,
+        generation: i64,
+        updated: i32,
+        desired: i32,
     },
 }
 
-pub async fn detect_changes(
-    deployment: &Deployment,
+impl DeploymentEvent {
+    /// 이벤트가 발생한 워크로드 (kind, namespace, name)
+    pub fn target(&self) -> (&str, &str, &str) {
+        match self {
+            DeploymentEvent::DeploymentStarted {
+                kind,
+                namespace,
+                name,
+                ..
+            }
+            | DeploymentEvent::DeploymentRestarted {
+                kind,
+                namespace,
+                name,
+                ..
+            }
+            | DeploymentEvent::DeploymentRestartCompleted {
+                kind,
+                namespace,
+                name,
+                ..
+            }
+            | DeploymentEvent::DeploymentRolledBack {
+                kind,
+                namespace,
+                name,
+                ..
+            }
+            | DeploymentEvent::DeploymentCompleted {
+                kind,
+                namespace,
+                name,
+                ..
+            }
+            | DeploymentEvent::ReplicaScaleStarted {
+                kind,
+                namespace,
+                name,
+                ..
+            }
+            | DeploymentEvent::ReplicaScaleCompleted {
+                kind,
+                namespace,
+                name,
+                ..
+            }
+            | DeploymentEvent::DeploymentStalled {
+                kind,
+                namespace,
+                name,
+                ..
+            }
+            | DeploymentEvent::DeploymentFailed {
+                kind,
+                namespace,
+                name,
+                ..
+            }
+            | DeploymentEvent::RolloutProgressed {
+                kind,
+                namespace,
+                name,
+                ..
+            } => (kind, namespace, name),
+        }
+    }
+
+    /// 워크로드별 상태 키와 같은 형식 ("kind/namespace/name")
+    pub fn key(&self) -> String {
+        let (kind, namespace, name) = self.target();
+        format!("{}/{}/{}", kind, namespace, name)
+    }
+
+    /// 이벤트가 가리키는 revision (스케일 이벤트는 None)
+    pub fn generation(&self) -> Option<i64> {
+        match self {
+            DeploymentEvent::DeploymentStarted { new_generation, .. }
+            | DeploymentEvent::DeploymentRestarted { new_generation, .. }
+            | DeploymentEvent::DeploymentRolledBack { new_generation, .. } => Some(*new_generation),
+            DeploymentEvent::DeploymentRestartCompleted { generation, .. }
+            | DeploymentEvent::DeploymentCompleted { generation, .. }
+            | DeploymentEvent::DeploymentStalled { generation, .. }
+            | DeploymentEvent::DeploymentFailed { generation, .. }
+            | DeploymentEvent::RolloutProgressed { generation, .. } => Some(*generation),
+            DeploymentEvent::ReplicaScaleStarted { .. }
+            | DeploymentEvent::ReplicaScaleCompleted { .. } => None,
+        }
+    }
+
+    /// 외부로 노출하는 이벤트 종류 이름 (webhook payload 등)
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            DeploymentEvent::DeploymentStarted { .. } => "deployment_started",
+            DeploymentEvent::DeploymentRestarted { .. } => "deployment_restarted",
+            DeploymentEvent::DeploymentRestartCompleted { .. } => "deployment_restart_completed",
+            DeploymentEvent::DeploymentRolledBack { .. } => "deployment_rolled_back",
+            DeploymentEvent::DeploymentCompleted { .. } => "deployment_completed",
+            DeploymentEvent::ReplicaScaleStarted { .. } => "replica_scale_started",
+            DeploymentEvent::ReplicaScaleCompleted { .. } => "replica_scale_completed",
+            DeploymentEvent::DeploymentStalled { .. } => "deployment_stalled",
+            DeploymentEvent::DeploymentFailed { .. } => "deployment_failed",
+            DeploymentEvent::RolloutProgressed { .. } => "rollout_progressed",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct DetectorConfig {
+    pub stall_timeout: Option<Duration>,
+}
+
+impl DetectorConfig {
+    pub fn from_env() -> Self {
+        // 0 또는 미설정이면 비활성화 (annotation으로 개별 설정은 가능)
+        let stall_timeout = std::env::var("STALL_TIMEOUT_SECONDS")
+            .ok()
+            .and_then(|s| s.parse::<u64>().ok())
+            .filter(|&secs| secs > 0)
+            .map(Duration::from_secs);
+
+        Self { stall_timeout }
+    }
+}
+
+pub async fn detect_changes<W: Workload>(
+    workload: &W,
     state_manager: &StateManager,
 ) -> Vec<DeploymentEvent> {
     let mut events = Vec::new();
 
-    let namespace = deployment
-        .metadata
-        .namespace
-        .as_deref()
-        .unwrap_or("default")
-        .to_string();
-    let name = deployment
-        .metadata
-        .name
-        .as_deref()
-        .unwrap_or("unknown")
-        .to_string();
-    let key = format!("{}/{}", namespace, name);
-
-    let mut current = extract_deployment_state(deployment);
+    let mut current = extract_state(workload);
+    let kind = current.kind.clone();
+    let namespace = current.namespace.clone();
+    let name = current.name.clone();
+    let key = workload.state_key();
+    let tracks_replicas = workload.tracks_replicas();
+
     let previous = state_manager.get(&key).await;
 
     // 이전 상태에서 last_completed_generation, last_scaled_replicas 복사
     if let Some(ref prev) = previous {
         current.last_completed_generation = prev.last_completed_generation;
         current.last_scaled_replicas = prev.last_scaled_replicas;
+        current.last_progress_milestone = prev.last_progress_milestone;
+        current.last_failed_generation = prev.last_failed_generation;
+        current.rollout_started_at = prev.rollout_started_at;
+        current.last_stalled_generation = prev.last_stalled_generation;
+        current.template_history = prev.template_history.clone();
+        current.pending_image_changes = prev.pending_image_changes.clone();
+        current.pending_template_changes = prev.pending_template_changes.clone();
+        current.pending_restart = prev.pending_restart;
     }
 
     match previous {
@@ -66,12 +261,27 @@ pub async fn detect_changes(
             // 초기 상태를 현재 값으로 설정하여 잘못된 완료 알림 방지
             current.last_completed_generation = current.generation;
             current.last_scaled_replicas = current.replicas;
+            current.last_failed_generation = current.generation;
+            record_template(&mut current);
+
+            // 이미 배포가 진행 중이면 완료 알림은 보낼 수 있도록 진행 중으로 간주
+            if workload.is_rollout_in_progress() {
+                log::info!(
+                    "Adopting in-flight rollout of {} {}/{} (revision: {})",
+                    kind,
+                    namespace,
+                    name,
+                    current.generation
+                );
+                current.last_completed_generation = current.generation - 1;
+                current.rollout_started_at = Some(now_seconds());
+            }
         }
         Some(prev) => {
             // 변경 여부 확인
-            let is_replica_change = current.replicas != prev.replicas;
+            let is_replica_change = tracks_replicas && current.replicas != prev.replicas;
             let is_generation_change = current.generation > prev.generation;
-            let is_pod_template_change = current.pod_template_hash != prev.pod_template_hash;
+            let is_pod_template_change = current.template_digest != prev.template_digest;
 
             // 1. Deployment spec 변경 감지 (generation 증가)
             if is_generation_change {
@@ -84,33 +294,101 @@ pub async fn detect_changes(
                         (None, None)
                     };
 
-                    events.push(DeploymentEvent::DeploymentStarted {
-                        namespace: namespace.clone(),
-                        name: name.clone(),
-                        old_generation: prev.generation,
-                        new_generation: current.generation,
-                        old_replicas,
-                        new_replicas,
-                    });
+                    // 완료 알림에서도 보여주기 위해 변경 내역 보관
+                    // (재시작 직후처럼 이전 template이 메모리에 없으면 변경 내역 없이 알림)
+                    let mut restart = None;
+                    if let (Some(old), Some(new)) =
+                        (parse_template(&prev.pod_template), workload.pod_template())
+                    {
+                        current.pending_image_changes = image_changes(&old, new);
+                        current.pending_template_changes = template_changes(&old, new);
+                        restart = restarted_at(&old, new);
+                    } else {
+                        current.pending_image_changes = Vec::new();
+                        current.pending_template_changes = TemplateChanges::default();
+                    }
+                    current.pending_restart = restart.is_some();
+
+                    // 과거에 배포했던 template으로 돌아간 경우 롤백으로 처리
+                    let rolled_back_to = current
+                        .template_history
+                        .iter()
+                        .find(|(_, d)| *d == current.template_digest)
+                        .map(|(generation, _)| *generation);
+
+                    match (restart, rolled_back_to) {
+                        // restartedAt annotation만 바뀐 경우 (kubectl rollout restart)
+                        (Some(restarted_at), _) => {
+                            events.push(DeploymentEvent::DeploymentRestarted {
+                                kind: kind.clone(),
+                                namespace: namespace.clone(),
+                                name: name.clone(),
+                                old_generation: prev.generation,
+                                new_generation: current.generation,
+                                restarted_at,
+                            });
+                        }
+                        (None, Some(rolled_back_to)) => {
+                            events.push(DeploymentEvent::DeploymentRolledBack {
+                                kind: kind.clone(),
+                                namespace: namespace.clone(),
+                                name: name.clone(),
+                                old_generation: prev.generation,
+                                new_generation: current.generation,
+                                rolled_back_to,
+                            });
+                        }
+                        (None, None) => {
+                            events.push(DeploymentEvent::DeploymentStarted {
+                                kind: kind.clone(),
+                                namespace: namespace.clone(),
+                                name: name.clone(),
+                                old_generation: prev.generation,
+                                new_generation: current.generation,
+                                old_replicas,
+                                new_replicas,
+                                image_changes: current.pending_image_changes.clone(),
+                                template_changes: current.pending_template_changes.clone(),
+                            });
+                        }
+                    }
+                    record_template(&mut current);
 
                     // replica 변경도 배포와 함께 처리됨
                     if is_replica_change {
                         current.last_scaled_replicas = current.replicas;
                     }
+                    current.last_progress_milestone = 0;
+                    current.rollout_started_at = Some(now_seconds());
                 } else {
                     // Pod template 동일하고 replica만 변경 -> replica 이벤트
-                    events.push(DeploymentEvent::ReplicaScaleStarted {
-                        namespace: namespace.clone(),
-                        name: name.clone(),
-                        old_replicas: prev.replicas,
-                        new_replicas: current.replicas,
-                    });
-                    // replica만 변경된 경우 배포 완료 알림이 가지 않도록 generation 기록
-                    current.last_completed_generation = current.generation;
+                    if is_replica_change {
+                        events.push(DeploymentEvent::ReplicaScaleStarted {
+                            kind: kind.clone(),
+                            namespace: namespace.clone(),
+                            name: name.clone(),
+                            old_replicas: prev.replicas,
+                            new_replicas: current.replicas,
+                        });
+                    }
+
+                    // 진행 중인 배포가 있거나 StatefulSet partition을 낮춰 단계적 배포를 이어가면
+                    // 완료 알림을 보낼 수 있도록 배포를 열어둠
+                    let rollout_open = prev.generation > prev.last_completed_generation;
+                    let partition_lowered = current.partition < prev.partition;
+                    if rollout_open || partition_lowered {
+                        if current.rollout_started_at.is_none() {
+                            current.rollout_started_at = Some(now_seconds());
+                        }
+                    } else {
+                        // replica만 변경된 경우 배포 완료 알림이 가지 않도록 generation 기록
+                        current.last_completed_generation = current.generation;
+                    }
                 }
             } else if is_replica_change {
                 // generation 변경 없이 replica만 변경 (이런 경우는 거의 없음)
                 events.push(DeploymentEvent::ReplicaScaleStarted {
+                    kind: kind.clone(),
                     namespace: namespace.clone(),
                     name: name.clone(),
                     old_replicas: prev.replicas,
@@ -118,10 +396,44 @@ pub async fn detect_changes(
                 });
             }
 
-            // 2. Deployment 완료 확인
-            if is_deployment_complete(&current)
+            // 노드 단위 롤아웃은 오래 걸리므로 25% 단위로 진행 상황 알림
+            if !tracks_replicas
+                && !current.rollout_complete
                 && current.generation > current.last_completed_generation
+                && current.replicas > 0
             {
+                let milestone = current.updated_replicas * 4 / current.replicas;
+                if milestone > current.last_progress_milestone && milestone < 4 {
+                    events.push(DeploymentEvent::RolloutProgressed {
+                        kind: kind.clone(),
+                        namespace: namespace.clone(),
+                        name: name.clone(),
+                        generation: current.generation,
+                        updated: current.updated_replicas,
+                        desired: current.replicas,
+                    });
+                    current.last_progress_milestone = milestone;
+                }
+            }
+
+            // 배포 실패 확인 (generation당 한 번만 알림)
+            if current.generation > current.last_failed_generation
+                && let Some((reason, message)) = workload.rollout_failure()
+            {
+                events.push(DeploymentEvent::DeploymentFailed {
+                    kind: kind.clone(),
+                    namespace: namespace.clone(),
+                    name: name.clone(),
+                    generation: current.generation,
+                    reason,
+                    message,
+                });
+                current.last_failed_generation = current.generation;
+                current.rollout_started_at = None;
+            }
+
+            // 2. Deployment 완료 확인
+            if current.rollout_complete && current.generation > current.last_completed_generation {
                 // 배포 시작 시 replica가 변경되었는지 확인
                 let replica_changed = if is_generation_change && is_replica_change {
                     Some((prev.replicas, current.replicas))
@@ -129,24 +441,41 @@ pub async fn detect_changes(
                     None
                 };
 
-                events.push(DeploymentEvent::DeploymentCompleted {
-                    namespace: namespace.clone(),
-                    name: name.clone(),
-                    generation: current.generation,
-                    replicas: current.replicas,
-                    replica_changed,
-                });
+                if current.pending_restart {
+                    events.push(DeploymentEvent::DeploymentRestartCompleted {
+                        kind: kind.clone(),
+                        namespace: namespace.clone(),
+                        name: name.clone(),
+                        generation: current.generation,
+                        replicas: current.replicas,
+                    });
+                } else {
+                    events.push(DeploymentEvent::DeploymentCompleted {
+                        kind: kind.clone(),
+                        namespace: namespace.clone(),
+                        name: name.clone(),
+                        generation: current.generation,
+                        replicas: current.replicas,
+                        replica_changed,
+                        image_changes: std::mem::take(&mut current.pending_image_changes),
+                        template_changes: std::mem::take(&mut current.pending_template_changes),
+                    });
+                }
                 // 완료된 generation 기록
                 current.last_completed_generation = current.generation;
+                current.rollout_started_at = None;
+                current.pending_restart = false;
             }
 
             // 3. Replica 변경 완료 확인
-            if current.replicas != current.last_scaled_replicas
+            if tracks_replicas
+                && current.replicas != current.last_scaled_replicas
                 && is_replicas_ready(&current)
                 && current.observed_generation == current.generation
                 && current.generation == current.last_completed_generation // 배포가 아닌 경우만
             {
                 events.push(DeploymentEvent::ReplicaScaleCompleted {
+                    kind: kind.clone(),
                     namespace: namespace.clone(),
                     name: name.clone(),
                     replicas: current.replicas,
@@ -163,38 +492,124 @@ pub async fn detect_changes(
     events
 }
 
-fn is_deployment_complete(state: &DeploymentState) -> bool {
-    state.observed_generation == state.generation
-        && state.ready_replicas == state.replicas
-        && state.available_replicas == state.replicas
-        && state.updated_replicas == state.replicas
+// 배포 시작 후 제한 시간 안에 완료되지 않은 워크로드를 찾음
+// watch 이벤트가 오지 않아도 판단해야 하므로 주기적으로 호출됨
+pub async fn detect_stalled(
+    state_manager: &StateManager,
+    config: &DetectorConfig,
+) -> Vec<DeploymentEvent> {
+    let mut events = Vec::new();
+    let now = now_seconds();
+
+    state_manager
+        .for_each_mut(|_, state| {
+            let Some(started_at) = state.rollout_started_at else {
+                return false;
+            };
+            let Some(timeout) = state
+                .stall_timeout_seconds
+                .map(Duration::from_secs)
+                .or(config.stall_timeout)
+            else {
+                return false;
+            };
+
+            let elapsed_seconds = now.saturating_sub(started_at);
+            if elapsed_seconds < timeout.as_secs()
+                || state.generation <= state.last_completed_generation
+                || state.generation <= state.last_stalled_generation
+            {
+                return false;
+            }
+
+            events.push(DeploymentEvent::DeploymentStalled {
+                kind: state.kind.clone(),
+                namespace: state.namespace.clone(),
+                name: state.name.clone(),
+                generation: state.generation,
+                elapsed_seconds,
+                replicas: state.replicas,
+                ready_replicas: state.ready_replicas,
+                updated_replicas: state.updated_replicas,
+            });
+            state.last_stalled_generation = state.generation;
+            true
+        })
+        .await;
+
+    events
+}
+
+// 같은 template이 여러 번 기록되지 않도록 기존 항목을 지우고 맨 뒤에 추가
+fn record_template(state: &mut DeploymentState) {
+    let template_digest = state.template_digest;
+    state
+        .template_history
+        .retain(|(_, d)| *d != template_digest);
+    state
+        .template_history
+        .push((state.generation, template_digest));
+
+    if state.template_history.len() > TEMPLATE_HISTORY_LIMIT {
+        let overflow = state.template_history.len() - TEMPLATE_HISTORY_LIMIT;
+        state.template_history.drain(..overflow);
+    }
+}
+
+// 저장소에 기록되므로 Rust 버전에 따라 바뀔 수 있는 DefaultHasher 대신 SHA-256 앞 8바이트 사용
+fn digest(value: &str) -> u64 {
+    let hash = Sha256::digest(value.as_bytes());
+    u64::from_be_bytes(hash[..8].try_into().expect("SHA-256 is 32 bytes"))
+}
+
+fn now_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
 }
 
 fn is_replicas_ready(state: &DeploymentState) -> bool {
     state.ready_replicas == state.replicas && state.available_replicas == state.replicas
 }
 
-fn extract_deployment_state(deployment: &Deployment) -> DeploymentState {
-    let metadata = &deployment.metadata;
-    let spec = deployment.spec.as_ref();
-    let status = deployment.status.as_ref();
-
+fn extract_state<W: Workload>(workload: &W) -> DeploymentState {
     // Pod template을 JSON으로 직렬화하여 해시 생성
-    let pod_template_hash = spec
-        .and_then(|s| serde_json::to_string(&s.template).ok())
-        .unwrap_or_default();
+    let pod_template = workload.template_fingerprint();
 
     DeploymentState {
-        namespace: metadata.namespace.clone().unwrap_or_default(),
-        name: metadata.name.clone().unwrap_or_default(),
-        generation: metadata.generation.unwrap_or(0),
-        replicas: spec.and_then(|s| s.replicas).unwrap_or(1),
-        ready_replicas: status.and_then(|s| s.ready_replicas).unwrap_or(0),
-        available_replicas: status.and_then(|s| s.available_replicas).unwrap_or(0),
-        updated_replicas: status.and_then(|s| s.updated_replicas).unwrap_or(0),
-        observed_generation: status.and_then(|s| s.observed_generation).unwrap_or(0),
-        pod_template_hash,
+        kind: workload.kind_name(),
+        namespace: workload.namespace_name(),
+        name: workload.workload_name(),
+        labels: workload.meta().labels.clone().unwrap_or_default(),
+        generation: workload.generation(),
+        replicas: workload.desired_replicas(),
+        ready_replicas: workload.ready_replicas(),
+        available_replicas: workload.available_replicas(),
+        updated_replicas: workload.updated_replicas(),
+        observed_generation: workload.observed_generation(),
+        template_digest: digest(&pod_template),
+        pod_template,
+        rollout_complete: workload.is_rollout_complete(),
+        partition: workload.partition(),
+        // 0은 STALL_TIMEOUT_SECONDS와 같이 유효하지 않은 값으로 보고 무시
+        stall_timeout_seconds: workload
+            .meta()
+            .annotations
+            .as_ref()
+            .and_then(|a| a.get(STALL_TIMEOUT_ANNOTATION))
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|&secs| secs > 0),
         last_completed_generation: 0, // 초기값, detect_changes에서 업데이트
         last_scaled_replicas: 0,      // 초기값, detect_changes에서 업데이트
+        last_progress_milestone: 0,
+        last_failed_generation: 0,
+        rollout_started_at: None,
+        last_stalled_generation: 0,
+        template_history: Vec::new(),
+        pending_image_changes: Vec::new(),
+        pending_template_changes: TemplateChanges::default(),
+        pending_restart: false,
+        slack_threads: BTreeMap::new(), // 알림 worker가 기록 (StateManager::update에서 유지)
     }
 }
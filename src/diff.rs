@@ -0,0 +1,340 @@
+use k8s_openapi::api::core::v1::{Container, PodTemplateSpec};
+use serde::{Deserialize, Serialize};
+
+// kubectl rollout restart가 Pod template에 기록하는 annotation
+const RESTARTED_AT_ANNOTATION: &str = "kubectl.kubernetes.io/restartedAt";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageChange {
+    pub container: String,
+    pub init: bool,
+    pub old_image: Option<String>, // None이면 새로 추가된 컨테이너
+    pub new_image: Option<String>, // None이면 제거된 컨테이너
+}
+
+impl ImageChange {
+    pub fn describe(&self) -> String {
+        let prefix = if self.init { "(init) " } else { "" };
+        format!(
+            "{}{}: {} → {}",
+            prefix,
+            self.container,
+            self.old_image.as_deref().unwrap_or("-"),
+            self.new_image.as_deref().unwrap_or("-")
+        )
+    }
+}
+
+// detector는 template을 JSON 문자열로 보관하므로 비교 전에 다시 파싱
+pub fn parse_template(template_json: &str) -> Option<PodTemplateSpec> {
+    serde_json::from_str(template_json).ok()
+}
+
+/// 두 Pod template 사이에서 이미지(태그/다이제스트)가 바뀐 컨테이너 목록
+pub fn image_changes(old: &PodTemplateSpec, new: &PodTemplateSpec) -> Vec<ImageChange> {
+    let mut changes = Vec::new();
+
+    let old_spec = old.spec.as_ref();
+    let new_spec = new.spec.as_ref();
+
+    let old_init = old_spec
+        .and_then(|s| s.init_containers.as_deref())
+        .unwrap_or_default();
+    let new_init = new_spec
+        .and_then(|s| s.init_containers.as_deref())
+        .unwrap_or_default();
+    diff_containers(old_init, new_init, true, &mut changes);
+
+    let old_containers = old_spec
+        .map(|s| s.containers.as_slice())
+        .unwrap_or_default();
+    let new_containers = new_spec
+        .map(|s| s.containers.as_slice())
+        .unwrap_or_default();
+    diff_containers(old_containers, new_containers, false, &mut changes);
+
+    changes
+}
+
+fn diff_containers(
+    old: &[Container],
+    new: &[Container],
+    init: bool,
+    changes: &mut Vec<ImageChange>,
+) {
+    for container in new {
+        match old.iter().find(|c| c.name == container.name) {
+            Some(prev) if prev.image == container.image => {}
+            prev => changes.push(ImageChange {
+                container: container.name.clone(),
+                init,
+                old_image: prev.and_then(|c| c.image.clone()),
+                new_image: container.image.clone(),
+            }),
+        }
+    }
+
+    for container in old {
+        if !new.iter().any(|c| c.name == container.name) {
+            changes.push(ImageChange {
+                container: container.name.clone(),
+                init,
+                old_image: container.image.clone(),
+                new_image: None,
+            });
+        }
+    }
+}
+
+/// 이미지 외에 바뀐 항목을 분류별로 요약 (값은 노출하지 않고 이름만 기록)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TemplateChanges {
+    pub env: Vec<String>,         // 바뀐 환경변수 이름
+    pub resources: Vec<String>,   // requests/limits가 바뀐 컨테이너 이름
+    pub probes: Vec<String>,      // probe가 바뀐 컨테이너 이름
+    pub volumes: Vec<String>,     // 바뀐 volume 이름
+    pub annotations: Vec<String>, // 바뀐 template annotation 키
+}
+
+impl TemplateChanges {
+    pub fn is_empty(&self) -> bool {
+        self.env.is_empty()
+            && self.resources.is_empty()
+            && self.probes.is_empty()
+            && self.volumes.is_empty()
+            && self.annotations.is_empty()
+    }
+
+    /// "env: A, B" 형태의 분류별 요약 목록
+    pub fn summary(&self) -> Vec<String> {
+        [
+            ("env", &self.env),
+            ("resources", &self.resources),
+            ("probes", &self.probes),
+            ("volumes", &self.volumes),
+            ("annotations", &self.annotations),
+        ]
+        .iter()
+        .filter(|(_, names)| !names.is_empty())
+        .map(|(category, names)| format!("{}: {}", category, names.join(", ")))
+        .collect()
+    }
+}
+
+/// 두 Pod template 사이에서 env, resources, probes, volumes, annotations 변경 요약
+pub fn template_changes(old: &PodTemplateSpec, new: &PodTemplateSpec) -> TemplateChanges {
+    let mut changes = TemplateChanges::default();
+
+    let old_spec = old.spec.as_ref();
+    let new_spec = new.spec.as_ref();
+
+    let old_containers = all_containers(old);
+    let new_containers = all_containers(new);
+
+    for container in &new_containers {
+        // 추가/제거된 컨테이너는 이미지 변경 내역에 이미 나타남
+        let Some(prev) = old_containers.iter().find(|c| c.name == container.name) else {
+            continue;
+        };
+
+        let old_env = prev.env.as_deref().unwrap_or_default();
+        let new_env = container.env.as_deref().unwrap_or_default();
+        for var in new_env {
+            if !old_env.contains(var) {
+                push_unique(&mut changes.env, &var.name);
+            }
+        }
+        for var in old_env {
+            if !new_env.iter().any(|v| v.name == var.name) {
+                push_unique(&mut changes.env, &var.name);
+            }
+        }
+
+        if prev.resources != container.resources {
+            push_unique(&mut changes.resources, &container.name);
+        }
+
+        if prev.liveness_probe != container.liveness_probe
+            || prev.readiness_probe != container.readiness_probe
+            || prev.startup_probe != container.startup_probe
+        {
+            push_unique(&mut changes.probes, &container.name);
+        }
+    }
+
+    let old_volumes = old_spec
+        .and_then(|s| s.volumes.as_deref())
+        .unwrap_or_default();
+    let new_volumes = new_spec
+        .and_then(|s| s.volumes.as_deref())
+        .unwrap_or_default();
+    for volume in new_volumes {
+        if !old_volumes.contains(volume) {
+            push_unique(&mut changes.volumes, &volume.name);
+        }
+    }
+    for volume in old_volumes {
+        if !new_volumes.iter().any(|v| v.name == volume.name) {
+            push_unique(&mut changes.volumes, &volume.name);
+        }
+    }
+
+    let old_annotations = old.metadata.as_ref().and_then(|m| m.annotations.as_ref());
+    let new_annotations = new.metadata.as_ref().and_then(|m| m.annotations.as_ref());
+    let mut keys: Vec<&String> = old_annotations
+        .into_iter()
+        .chain(new_annotations)
+        .flat_map(|a| a.keys())
+        .collect();
+    keys.sort();
+    keys.dedup();
+    for key in keys {
+        if old_annotations.and_then(|a| a.get(key)) != new_annotations.and_then(|a| a.get(key)) {
+            changes.annotations.push(key.clone());
+        }
+    }
+
+    changes
+}
+
+/// restartedAt annotation만 바뀐 경우(kubectl rollout restart) 새 restartedAt 값 반환
+pub fn restarted_at(old: &PodTemplateSpec, new: &PodTemplateSpec) -> Option<String> {
+    let new_restarted_at = new
+        .metadata
+        .as_ref()
+        .and_then(|m| m.annotations.as_ref())
+        .and_then(|a| a.get(RESTARTED_AT_ANNOTATION))?;
+
+    let mut old = old.clone();
+    let mut new = new.clone();
+    for template in [&mut old, &mut new] {
+        if let Some(metadata) = template.metadata.as_mut() {
+            if let Some(annotations) = metadata.annotations.as_mut() {
+                annotations.remove(RESTARTED_AT_ANNOTATION);
+            }
+            // 처음 restart하면 annotations가 없던 template에 restartedAt 하나만 생기므로
+            // 지운 뒤 비어 있으면 없는 것과 같게 취급
+            if metadata.annotations.as_ref().is_some_and(|a| a.is_empty()) {
+                metadata.annotations = None;
+            }
+        }
+        if template.metadata == Some(Default::default()) {
+            template.metadata = None;
+        }
+    }
+
+    (old == new).then(|| new_restarted_at.clone())
+}
+
+fn all_containers(template: &PodTemplateSpec) -> Vec<&Container> {
+    let spec = template.spec.as_ref();
+    let init = spec
+        .and_then(|s| s.init_containers.as_deref())
+        .unwrap_or_default();
+    let containers = spec.map(|s| s.containers.as_slice()).unwrap_or_default();
+    init.iter().chain(containers).collect()
+}
+
+fn push_unique(names: &mut Vec<String>, name: &str) {
+    if !names.iter().any(|n| n == name) {
+        names.push(name.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn template(image: &str, password: &str, restarted_at: Option<&str>) -> PodTemplateSpec {
+        let annotations = match restarted_at {
+            Some(value) => json!({ RESTARTED_AT_ANNOTATION: value }),
+            None => json!({}),
+        };
+        serde_json::from_value(json!({
+            "metadata": { "annotations": annotations },
+            "spec": {
+                "containers": [{
+                    "name": "api",
+                    "image": image,
+                    "env": [
+                        { "name": "LOG_LEVEL", "value": "info" },
+                        { "name": "DB_PASSWORD", "value": password },
+                    ],
+                }],
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn env_value_change_reports_name_only() {
+        let old = template("api:1.0", "old-secret", None);
+        let new = template("api:1.0", "new-secret", None);
+
+        let changes = template_changes(&old, &new);
+        assert_eq!(changes.env, vec!["DB_PASSWORD"]);
+        assert!(changes.resources.is_empty() && changes.annotations.is_empty());
+
+        let summary = changes.summary().join("; ");
+        assert_eq!(summary, "env: DB_PASSWORD");
+        assert!(!summary.contains("secret"));
+
+        assert!(image_changes(&old, &new).is_empty());
+        assert_eq!(restarted_at(&old, &new), None);
+    }
+
+    #[test]
+    fn restart_only_change_is_a_restart() {
+        let old = template("api:1.0", "secret", None);
+        let new = template("api:1.0", "secret", Some("2025-01-01T00:00:00Z"));
+        assert_eq!(
+            restarted_at(&old, &new).as_deref(),
+            Some("2025-01-01T00:00:00Z")
+        );
+
+        // annotations 필드가 아예 없던 template을 처음 restart
+        let mut bare = old.clone();
+        bare.metadata = None;
+        assert_eq!(
+            restarted_at(&bare, &new).as_deref(),
+            Some("2025-01-01T00:00:00Z")
+        );
+        let mut labeled = bare.clone();
+        labeled.metadata = Some(Default::default());
+        labeled.metadata.as_mut().unwrap().labels =
+            Some([("app".to_string(), "api".to_string())].into());
+        let mut labeled_restart = labeled.clone();
+        labeled_restart.metadata.as_mut().unwrap().annotations = Some(
+            [(
+                RESTARTED_AT_ANNOTATION.to_string(),
+                "2025-01-01T00:00:00Z".to_string(),
+            )]
+            .into(),
+        );
+        assert_eq!(
+            restarted_at(&labeled, &labeled_restart).as_deref(),
+            Some("2025-01-01T00:00:00Z")
+        );
+
+        // 이미 restart한 적이 있는 template을 다시 restart
+        let again = template("api:1.0", "secret", Some("2025-01-02T00:00:00Z"));
+        assert_eq!(
+            restarted_at(&new, &again).as_deref(),
+            Some("2025-01-02T00:00:00Z")
+        );
+        assert!(image_changes(&new, &again).is_empty());
+        assert!(template_changes(&new, &again).env.is_empty());
+    }
+
+    #[test]
+    fn restart_with_image_change_is_a_deployment() {
+        let old = template("api:1.0", "secret", Some("2025-01-01T00:00:00Z"));
+        let new = template("api:1.1", "secret", Some("2025-01-02T00:00:00Z"));
+        assert_eq!(restarted_at(&old, &new), None);
+
+        let changes = image_changes(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].describe(), "api: api:1.0 → api:1.1");
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tokio::sync::mpsc;
+
+use crate::detector::DeploymentEvent;
+use crate::notifier::NotifierRegistry;
+
+const DEFAULT_WORKERS: usize = 4;
+const DEFAULT_QUEUE_SIZE: usize = 256;
+
+/// 알림 큐 상태 (/metrics로 노출)
+#[derive(Default)]
+pub struct QueueMetrics {
+    enqueued: AtomicU64,
+    delivered: AtomicU64,
+    failed: AtomicU64,  // 하나 이상의 backend로 전송하지 못한 이벤트 수
+    blocked: AtomicU64, // 큐가 가득 차서 watch 처리가 대기한 횟수
+    depth: AtomicU64,
+}
+
+impl QueueMetrics {
+    /// Prometheus text format
+    pub fn render(&self) -> String {
+        format!(
+            "# TYPE bellboy_notifications_enqueued_total counter\n\
+             bellboy_notifications_enqueued_total {}\n\
+             # TYPE bellboy_notifications_delivered_total counter\n\
+             bellboy_notifications_delivered_total {}\n\
+             # TYPE bellboy_notifications_failed_total counter\n\
+             bellboy_notifications_failed_total {}\n\
+             # TYPE bellboy_notification_queue_blocked_total counter\n\
+             bellboy_notification_queue_blocked_total {}\n\
+             # TYPE bellboy_notification_queue_depth gauge\n\
+             bellboy_notification_queue_depth {}\n",
+            self.enqueued.load(Ordering::Relaxed),
+            self.delivered.load(Ordering::Relaxed),
+            self.failed.load(Ordering::Relaxed),
+            self.blocked.load(Ordering::Relaxed),
+            self.depth.load(Ordering::Relaxed),
+        )
+    }
+}
+
+/// detect_changes와 알림 전송 사이의 bounded 큐
+///
+/// 같은 워크로드의 이벤트는 항상 같은 worker로 보내 순서를 보장하고,
+/// 느린 전송이 다른 워크로드의 처리를 막지 않도록 worker 여러 개가 병렬로 전송
+#[derive(Clone)]
+pub struct Dispatcher {
+    senders: Vec<mpsc::Sender<DeploymentEvent>>,
+    metrics: Arc<QueueMetrics>,
+}
+
+impl Dispatcher {
+    pub fn new(notifiers: NotifierRegistry, workers: usize, queue_size: usize) -> Self {
+        let workers = workers.max(1);
+        let per_worker = (queue_size / workers).max(1);
+        let metrics = Arc::new(QueueMetrics::default());
+        let notifiers = Arc::new(notifiers);
+
+        let senders = (0..workers)
+            .map(|_| {
+                let (sender, receiver) = mpsc::channel(per_worker);
+                tokio::spawn(run_worker(receiver, notifiers.clone(), metrics.clone()));
+                sender
+            })
+            .collect();
+
+        Self { senders, metrics }
+    }
+
+    /// NOTIFIER_WORKERS, NOTIFY_QUEUE_SIZE 환경 변수로 설정
+    pub fn from_env(notifiers: NotifierRegistry) -> Self {
+        let workers = std::env::var("NOTIFIER_WORKERS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_WORKERS);
+        let queue_size = std::env::var("NOTIFY_QUEUE_SIZE")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_QUEUE_SIZE);
+
+        log::info!(
+            "Notification queue: {} workers, size {}",
+            workers,
+            queue_size
+        );
+        Self::new(notifiers, workers, queue_size)
+    }
+
+    pub fn metrics(&self) -> Arc<QueueMetrics> {
+        self.metrics.clone()
+    }
+
+    /// 큐가 가득 차 있으면 자리가 날 때까지 대기 (backpressure)
+    pub async fn dispatch(&self, event: DeploymentEvent) {
+        let mut hasher = DefaultHasher::new();
+        event.key().hash(&mut hasher);
+        let sender = &self.senders[hasher.finish() as usize % self.senders.len()];
+
+        self.metrics.enqueued.fetch_add(1, Ordering::Relaxed);
+        self.metrics.depth.fetch_add(1, Ordering::Relaxed);
+
+        let event = match sender.try_send(event) {
+            Ok(()) => return,
+            Err(mpsc::error::TrySendError::Full(event)) => {
+                self.metrics.blocked.fetch_add(1, Ordering::Relaxed);
+                log::warn!("Notification queue is full, waiting");
+                event
+            }
+            Err(mpsc::error::TrySendError::Closed(event)) => event,
+        };
+
+        if sender.send(event).await.is_err() {
+            self.metrics.depth.fetch_sub(1, Ordering::Relaxed);
+            log::error!("Notification worker stopped, dropping event");
+        }
+    }
+}
+
+async fn run_worker(
+    mut receiver: mpsc::Receiver<DeploymentEvent>,
+    notifiers: Arc<NotifierRegistry>,
+    metrics: Arc<QueueMetrics>,
+) {
+    while let Some(event) = receiver.recv().await {
+        let delivered = notifiers.notify(&event).await;
+        metrics.depth.fetch_sub(1, Ordering::Relaxed);
+        if delivered {
+            metrics.delivered.fetch_add(1, Ordering::Relaxed);
+        } else {
+            metrics.failed.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
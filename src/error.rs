@@ -0,0 +1,77 @@
+use std::fmt;
+
+use kube::runtime::watcher;
+
+/// 재시도해도 해결되지 않아 프로세스를 종료해야 하는 오류
+#[derive(Debug)]
+pub enum BellboyError {
+    /// kubeconfig 또는 in-cluster 설정을 읽지 못함
+    Config(kube::Error),
+    /// 인증 실패 또는 RBAC 권한 부족 (401/403)
+    Unauthorized {
+        kind: String,
+        source: watcher::Error,
+    },
+    /// watch를 지원하지 않는 리소스
+    Unwatchable {
+        kind: String,
+        source: watcher::Error,
+    },
+    /// 알림 설정 오류 (webhook header, proxy, CA bundle 등)
+    Notifier(String),
+}
+
+impl fmt::Display for BellboyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BellboyError::Config(e) => write!(f, "failed to load Kubernetes config: {}", e),
+            BellboyError::Unauthorized { kind, source } => write!(
+                f,
+                "not authorized to watch {} (check the ServiceAccount token and RBAC rules): {}",
+                kind, source
+            ),
+            BellboyError::Unwatchable { kind, source } => {
+                write!(f, "cannot watch {}: {}", kind, source)
+            }
+            BellboyError::Notifier(reason) => {
+                write!(f, "invalid notification settings: {}", reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BellboyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BellboyError::Config(e) => Some(e),
+            BellboyError::Unauthorized { source, .. } => Some(source),
+            BellboyError::Unwatchable { source, .. } => Some(source),
+            BellboyError::Notifier(_) => None,
+        }
+    }
+}
+
+impl BellboyError {
+    /// watch 오류 중 재시도해도 소용없는 오류만 변환하고 나머지는 그대로 돌려줌
+    pub fn from_watch_error(kind: &str, error: watcher::Error) -> Result<Self, watcher::Error> {
+        let api_code = match &error {
+            watcher::Error::InitialListFailed(kube::Error::Api(status))
+            | watcher::Error::WatchStartFailed(kube::Error::Api(status))
+            | watcher::Error::WatchFailed(kube::Error::Api(status)) => Some(status.code),
+            watcher::Error::WatchError(status) => Some(status.code),
+            _ => None,
+        };
+
+        match (api_code, &error) {
+            (Some(401 | 403), _) => Ok(BellboyError::Unauthorized {
+                kind: kind.to_string(),
+                source: error,
+            }),
+            (_, watcher::Error::NoResourceVersion) => Ok(BellboyError::Unwatchable {
+                kind: kind.to_string(),
+                source: error,
+            }),
+            _ => Err(error),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use crate::dispatcher::QueueMetrics;
+
+#[derive(Clone, Serialize)]
+struct WatcherStatus {
+    healthy: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_error: Option<String>,
+}
+
+/// watcher별 상태를 모아 /readyz로 노출
+#[derive(Clone, Default)]
+pub struct HealthStatus {
+    watchers: Arc<Mutex<BTreeMap<String, WatcherStatus>>>,
+}
+
+impl HealthStatus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_ok(&self, watcher: &str) {
+        let mut watchers = self.watchers.lock().unwrap();
+        watchers.insert(
+            watcher.to_string(),
+            WatcherStatus {
+                healthy: true,
+                last_error: None,
+            },
+        );
+    }
+
+    pub fn record_error(&self, watcher: &str, error: String) {
+        let mut watchers = self.watchers.lock().unwrap();
+        watchers.insert(
+            watcher.to_string(),
+            WatcherStatus {
+                healthy: false,
+                last_error: Some(error),
+            },
+        );
+    }
+
+    fn is_ready(&self) -> bool {
+        let watchers = self.watchers.lock().unwrap();
+        watchers.values().all(|w| w.healthy)
+    }
+
+    fn to_json(&self) -> String {
+        let watchers = self.watchers.lock().unwrap();
+        serde_json::json!({ "watchers": *watchers }).to_string()
+    }
+}
+
+/// /healthz: 프로세스가 살아있으면 항상 200 (liveness)
+/// /readyz: 모든 watcher가 정상이면 200, 재시도 중인 watcher가 있으면 503 (readiness)
+/// /metrics: 알림 큐 상태 (Prometheus text format)
+pub async fn serve(status: HealthStatus, metrics: Arc<QueueMetrics>, port: u16) {
+    let listener = match TcpListener::bind(("0.0.0.0", port)).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            log::error!("Failed to start health server on port {}: {}", port, e);
+            return;
+        }
+    };
+    log::info!("Health server listening on port {}", port);
+
+    loop {
+        let Ok((mut socket, _)) = listener.accept().await else {
+            continue;
+        };
+        let status = status.clone();
+        let metrics = metrics.clone();
+
+        tokio::spawn(async move {
+            let mut buf = [0u8; 1024];
+            let Ok(n) = socket.read(&mut buf).await else {
+                return;
+            };
+            let request = String::from_utf8_lossy(&buf[..n]);
+            let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+            let (code, body) = match path {
+                "/healthz" => ("200 OK", "ok".to_string()),
+                "/readyz" if status.is_ready() => ("200 OK", status.to_json()),
+                "/readyz" => ("503 Service Unavailable", status.to_json()),
+                "/metrics" => ("200 OK", metrics.render()),
+                _ => ("404 Not Found", "not found".to_string()),
+            };
+
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                code,
+                body.len(),
+                body
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        });
+    }
+}
@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use reqwest::{Certificate, Client, NoProxy, Proxy};
+
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 모든 알림 전송이 공유하는 HTTP client (connection pool 재사용)
+///
+/// - HTTPS_PROXY / NO_PROXY: egress proxy
+/// - NOTIFY_CA_BUNDLE: 추가로 신뢰할 CA 인증서 (PEM, 여러 개 가능)
+/// - NOTIFY_CONNECT_TIMEOUT_SECONDS, NOTIFY_TIMEOUT_SECONDS: 연결/요청 timeout
+pub fn client_from_env() -> Result<Client, String> {
+    let connect_timeout =
+        env_seconds("NOTIFY_CONNECT_TIMEOUT_SECONDS").unwrap_or(DEFAULT_CONNECT_TIMEOUT);
+    let timeout = env_seconds("NOTIFY_TIMEOUT_SECONDS").unwrap_or(DEFAULT_REQUEST_TIMEOUT);
+
+    let mut builder = Client::builder()
+        .connect_timeout(connect_timeout)
+        .timeout(timeout);
+
+    if let Some(proxy_url) = env_any(&["HTTPS_PROXY", "https_proxy"]) {
+        let proxy = Proxy::https(&proxy_url)
+            .map_err(|e| format!("invalid HTTPS_PROXY: {}", e))?
+            .no_proxy(NoProxy::from_env());
+        // 로그에 user:password가 남지 않도록 host까지만 사용
+        let host = reqwest::Url::parse(&proxy_url)
+            .ok()
+            .and_then(|u| u.host_str().map(|h| h.to_string()))
+            .unwrap_or_default();
+        log::info!("Sending notifications through proxy: {}", host);
+        builder = builder.proxy(proxy);
+    }
+
+    if let Ok(path) = std::env::var("NOTIFY_CA_BUNDLE") {
+        let pem = std::fs::read(&path).map_err(|e| format!("failed to read {}: {}", path, e))?;
+        let certificates = Certificate::from_pem_bundle(&pem)
+            .map_err(|e| format!("invalid CA bundle {}: {}", path, e))?;
+        log::info!(
+            "Trusting {} additional CA certificates from {}",
+            certificates.len(),
+            path
+        );
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    builder.build().map_err(|e| e.to_string())
+}
+
+fn env_seconds(name: &str) -> Option<Duration> {
+    std::env::var(name)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .map(Duration::from_secs)
+}
+
+fn env_any(names: &[&str]) -> Option<String> {
+    names
+        .iter()
+        .find_map(|name| std::env::var(name).ok())
+        .filter(|v| !v.is_empty())
+}
@@ -0,0 +1,192 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use k8s_openapi::api::coordination::v1::{Lease, LeaseSpec};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::MicroTime;
+use k8s_openapi::jiff::Timestamp;
+use kube::api::{ObjectMeta, PostParams};
+use kube::{Api, Client};
+
+const LEASE_DURATION: Duration = Duration::from_secs(15);
+// standby가 Lease를 가져가기 전에 확실히 물러나도록 LEASE_DURATION보다 짧게 설정
+const RENEW_DEADLINE: Duration = Duration::from_secs(10);
+const RENEW_INTERVAL: Duration = Duration::from_secs(5);
+const RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// coordination.k8s.io Lease를 이용한 leader election
+///
+/// 여러 replica 중 Lease를 가진 하나만 watch와 알림을 수행하고
+/// 나머지는 Lease가 만료될 때까지 대기
+pub struct LeaderElector {
+    api: Api<Lease>,
+    lease_name: String,
+    identity: String,
+    // 다른 replica가 가진 Lease를 마지막으로 본 값과 그 값이 바뀐 로컬 시각
+    // (노드 간 시계 차이가 있을 수 있으므로 renewTime을 로컬 시계와 직접 비교하지 않음)
+    observed: Mutex<Option<(LeaseRecord, tokio::time::Instant)>>,
+}
+
+#[derive(PartialEq)]
+struct LeaseRecord {
+    holder_identity: Option<String>,
+    renew_time: Option<Timestamp>,
+    resource_version: Option<String>,
+}
+
+impl LeaderElector {
+    pub fn new(client: Client, namespace: &str, lease_name: &str, identity: &str) -> Self {
+        Self {
+            api: Api::namespaced(client, namespace),
+            lease_name: lease_name.to_string(),
+            identity: identity.to_string(),
+            observed: Mutex::new(None),
+        }
+    }
+
+    /// LEADER_ELECTION=true일 때만 생성
+    pub fn from_env(client: Client) -> Option<Self> {
+        let enabled = std::env::var("LEADER_ELECTION")
+            .map(|v| v == "true")
+            .unwrap_or(false);
+        if !enabled {
+            return None;
+        }
+
+        let namespace = std::env::var("POD_NAMESPACE").unwrap_or("default".to_string());
+        let lease_name = std::env::var("LEASE_NAME").unwrap_or("bellboy".to_string());
+        // POD_NAME이 없으면 hostname (컨테이너에서는 Pod 이름과 같음)
+        let identity = std::env::var("POD_NAME")
+            .or_else(|_| std::env::var("HOSTNAME"))
+            .unwrap_or("bellboy".to_string());
+
+        Some(Self::new(client, &namespace, &lease_name, &identity))
+    }
+
+    /// leader가 될 때까지 대기
+    pub async fn acquire(&self) {
+        log::info!(
+            "Waiting for leadership (lease: {}, identity: {})",
+            self.lease_name,
+            self.identity
+        );
+        loop {
+            match self.try_acquire_or_renew().await {
+                Ok(true) => {
+                    log::info!("Acquired leadership");
+                    return;
+                }
+                Ok(false) => {}
+                Err(e) => log::warn!("Leader election failed: {}", e),
+            }
+            tokio::time::sleep(RETRY_INTERVAL).await;
+        }
+    }
+
+    /// Lease를 주기적으로 갱신하다가 leadership을 잃으면 반환
+    pub async fn keep_renewing(&self) {
+        let mut interval = tokio::time::interval(RENEW_INTERVAL);
+        let mut last_renewed = tokio::time::Instant::now();
+        loop {
+            interval.tick().await;
+            // Lease의 renewTime은 요청을 보낸 시각이므로 응답 시각이 아닌 시작 시각 기준
+            let attempt_started = tokio::time::Instant::now();
+            let remaining = RENEW_DEADLINE.saturating_sub(last_renewed.elapsed());
+            match tokio::time::timeout(remaining, self.try_acquire_or_renew()).await {
+                Ok(Ok(true)) => last_renewed = attempt_started,
+                Ok(Ok(false)) => {
+                    log::error!("Lost leadership to another replica");
+                    return;
+                }
+                // 일시적인 API 오류는 renew deadline까지 재시도
+                Ok(Err(e)) => {
+                    log::warn!("Failed to renew lease: {}", e);
+                    if last_renewed.elapsed() >= RENEW_DEADLINE {
+                        log::error!("Renew deadline exceeded");
+                        return;
+                    }
+                }
+                Err(_) => {
+                    log::error!("Renew deadline exceeded");
+                    return;
+                }
+            }
+        }
+    }
+
+    // Lease가 마지막으로 갱신된 것을 본 뒤 지난 시간 (처음 보면 0부터 시작)
+    fn unchanged_for(&self, record: LeaseRecord) -> Duration {
+        let mut observed = self.observed.lock().unwrap_or_else(|e| e.into_inner());
+        match &*observed {
+            Some((last, since)) if *last == record => since.elapsed(),
+            _ => {
+                *observed = Some((record, tokio::time::Instant::now()));
+                Duration::ZERO
+            }
+        }
+    }
+
+    // 다른 replica가 유효한 Lease를 가지고 있으면 false
+    async fn try_acquire_or_renew(&self) -> Result<bool, kube::Error> {
+        let now = Timestamp::now();
+
+        let Some(mut lease) = self.api.get_opt(&self.lease_name).await? else {
+            let lease = Lease {
+                metadata: ObjectMeta {
+                    name: Some(self.lease_name.clone()),
+                    ..Default::default()
+                },
+                spec: Some(LeaseSpec {
+                    holder_identity: Some(self.identity.clone()),
+                    lease_duration_seconds: Some(LEASE_DURATION.as_secs() as i32),
+                    acquire_time: Some(MicroTime(now)),
+                    renew_time: Some(MicroTime(now)),
+                    lease_transitions: Some(0),
+                    ..Default::default()
+                }),
+            };
+            return match self.api.create(&PostParams::default(), &lease).await {
+                Ok(_) => Ok(true),
+                // 다른 replica가 먼저 생성한 경우
+                Err(kube::Error::Api(e)) if e.code == 409 => Ok(false),
+                Err(e) => Err(e),
+            };
+        };
+
+        let spec = lease.spec.get_or_insert_with(Default::default);
+        let is_holder = spec.holder_identity.as_deref() == Some(self.identity.as_str());
+
+        if !is_holder {
+            let duration = spec
+                .lease_duration_seconds
+                .map(|s| Duration::from_secs(s.max(0) as u64))
+                .unwrap_or(LEASE_DURATION);
+            let record = LeaseRecord {
+                holder_identity: spec.holder_identity.clone(),
+                renew_time: spec.renew_time.as_ref().map(|t| t.0),
+                resource_version: lease.metadata.resource_version.clone(),
+            };
+            let expired = spec.holder_identity.as_deref().is_none_or(str::is_empty)
+                || self.unchanged_for(record) > duration;
+            if !expired {
+                return Ok(false);
+            }
+
+            spec.holder_identity = Some(self.identity.clone());
+            spec.acquire_time = Some(MicroTime(now));
+            spec.lease_transitions = Some(spec.lease_transitions.unwrap_or(0) + 1);
+        }
+        spec.lease_duration_seconds = Some(LEASE_DURATION.as_secs() as i32);
+        spec.renew_time = Some(MicroTime(now));
+
+        // resourceVersion이 포함되어 있으므로 동시에 갱신하면 한쪽은 409로 실패
+        match self
+            .api
+            .replace(&self.lease_name, &PostParams::default(), &lease)
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(kube::Error::Api(e)) if e.code == 409 => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -1,18 +1,44 @@
 mod detector;
+mod diff;
+mod dispatcher;
+mod error;
+mod health;
+mod http;
+mod leader;
 mod notifier;
+mod persistence;
+mod retry;
 mod state;
+mod workload;
 
+use std::collections::HashSet;
 use std::env;
+use std::fmt::Debug;
+use std::time::Duration;
 
-use detector::detect_changes;
-use futures::TryStreamExt;
-use k8s_openapi::api::apps::v1::Deployment;
+use detector::{DetectorConfig, detect_changes, detect_stalled};
+use dispatcher::Dispatcher;
+use error::BellboyError;
+use futures::StreamExt;
+use health::HealthStatus;
+use k8s_openapi::{
+    NamespaceResourceScope,
+    api::apps::v1::{DaemonSet, Deployment, StatefulSet},
+};
 use kube::{
-    Api, Client,
+    Api, Client, Resource,
     runtime::{WatchStreamExt, watcher},
 };
-use notifier::{NotifierConfig, notify};
+use leader::LeaderElector;
+use notifier::{NotifierConfig, NotifierRegistry};
+use persistence::store_from_env;
+use serde::de::DeserializeOwned;
 use state::StateManager;
+use workload::Workload;
+
+const STALL_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const PERSIST_INTERVAL: Duration = Duration::from_secs(10);
+const DEFAULT_HEALTH_PORT: u16 = 8080;
 
 fn setup_logging() {
     unsafe {
@@ -23,51 +49,190 @@ fn setup_logging() {
     env_logger::init();
 }
 
+fn workload_api<K>(client: Client) -> Api<K>
+where
+    K: Resource<Scope = NamespaceResourceScope>,
+    <K as Resource>::DynamicType: Default,
+{
+    match env::var("NAMESPACE") {
+        Ok(namespace) => Api::namespaced(client, &namespace),
+        Err(_) => Api::all(client),
+    }
+}
+
+// 워크로드 종류 하나를 watch하며 변경 사항을 감지하고 알림 전송
+// 일시적인 API 오류는 backoff 후 재시도하고, 인증/설정 오류만 반환
+async fn watch_workload<K>(
+    api: Api<K>,
+    state_manager: &StateManager,
+    dispatcher: &Dispatcher,
+    health: &HealthStatus,
+) -> Result<(), BellboyError>
+where
+    K: Workload + Clone + DeserializeOwned + Debug + Send + 'static,
+{
+    let kind = K::kind(&()).to_string();
+    let mut stream = watcher(api, Default::default()).default_backoff().boxed();
+    // 처음 목록(및 재연결 후 다시 받은 목록)에 없는 워크로드는 상태에서 삭제
+    let mut listed = HashSet::new();
+
+    while let Some(result) = stream.next().await {
+        match result {
+            Ok(event) => {
+                health.record_ok(&kind);
+
+                match event {
+                    watcher::Event::Init => listed.clear(),
+                    watcher::Event::InitDone => {
+                        state_manager
+                            .retain_kind(&kind, &std::mem::take(&mut listed))
+                            .await;
+                    }
+                    watcher::Event::Apply(workload) | watcher::Event::InitApply(workload) => {
+                        listed.insert(workload.state_key());
+                        let events = detect_changes(&workload, state_manager).await;
+
+                        for event in events {
+                            dispatcher.dispatch(event).await;
+                        }
+                    }
+                    // 삭제된 워크로드는 더 이상 멈춤 알림 대상이 아니므로 상태도 삭제
+                    watcher::Event::Delete(workload) => {
+                        state_manager.remove(&workload.state_key()).await;
+                    }
+                }
+            }
+            Err(e) => match BellboyError::from_watch_error(&kind, e) {
+                Ok(fatal) => return Err(fatal),
+                Err(e) => {
+                    log::warn!("{} watch failed, retrying: {}", kind, e);
+                    health.record_error(&kind, e.to_string());
+                }
+            },
+        }
+    }
+
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() {
     setup_logging();
 
-    let client = Client::try_default()
-        .await
-        .expect("Failed to create client");
+    if let Err(e) = run().await {
+        log::error!("{}", e);
+        std::process::exit(1);
+    }
+}
 
-    // NAMESPACE 환경 변수로 특정 네임스페이스만 watch 가능
-    let deployments: Api<Deployment> = match env::var("NAMESPACE") {
-        Ok(namespace) => {
-            log::info!("Watching namespace: {}", namespace);
-            Api::namespaced(client, &namespace)
-        }
-        Err(_) => {
-            log::info!("Watching all namespaces");
-            Api::all(client)
-        }
+async fn run() -> Result<(), BellboyError> {
+    let client = Client::try_default().await.map_err(BellboyError::Config)?;
+
+    let notifier_config = NotifierConfig::from_env().map_err(BellboyError::Notifier)?;
+
+    // STATE_BACKEND 설정 시 재시작 후에도 진행 중이던 배포를 이어서 추적
+    // (Slack thread도 여기에 기록하므로 알림 backend보다 먼저 생성)
+    let state_manager = match store_from_env(client.clone()) {
+        Some(store) => StateManager::with_store(store),
+        None => StateManager::new(),
     };
-    let state_manager = StateManager::new();
-    let notifier_config = NotifierConfig::from_env();
 
-    // Slack 설정 확인 및 로깅
-    if notifier_config.has_slack_config() {
-        log::info!("Slack notification enabled");
-        log::info!("Language: {:?}", notifier_config.language);
+    let notifiers = NotifierRegistry::from_config(&notifier_config, &state_manager)
+        .map_err(BellboyError::Notifier)?;
+
+    // 알림 backend 설정 확인 및 로깅
+    if notifiers.names().is_empty() {
+        log::info!("No notification backend configured, logging only");
     } else {
-        log::info!("Slack notification disabled (set SLACK_TOKEN and SLACK_CHANNEL to enable)");
+        log::info!("Notification enabled: {}", notifiers.names().join(", "));
+        log::info!("Language: {:?}", notifier_config.language);
+    }
+
+    // 느린 알림 전송이 watch 처리를 막지 않도록 큐를 거쳐 worker에서 전송
+    let dispatcher = Dispatcher::from_env(notifiers);
+
+    let health = HealthStatus::new();
+    let health_port = env::var("HEALTH_PORT")
+        .ok()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(DEFAULT_HEALTH_PORT);
+    tokio::spawn(health::serve(
+        health.clone(),
+        dispatcher.metrics(),
+        health_port,
+    ));
+
+    // NAMESPACE 환경 변수로 특정 네임스페이스만 watch 가능
+    match env::var("NAMESPACE") {
+        Ok(namespace) => log::info!("Watching namespace: {}", namespace),
+        Err(_) => log::info!("Watching all namespaces"),
+    }
+    let deployments: Api<Deployment> = workload_api(client.clone());
+    let statefulsets: Api<StatefulSet> = workload_api(client.clone());
+    let daemonsets: Api<DaemonSet> = workload_api(client.clone());
+
+    // 여러 replica를 띄운 경우 leader만 watch와 알림을 수행
+    // leader가 바뀌면 저장된 상태를 이어받아 그 사이의 변경을 감지
+    let leader_elector = LeaderElector::from_env(client.clone());
+    if let Some(elector) = &leader_elector {
+        elector.acquire().await;
     }
 
-    watcher(deployments, Default::default())
-        .applied_objects()
-        .try_for_each(|deployment| {
-            let state_manager = state_manager.clone();
-            let notifier_config = notifier_config.clone();
-            async move {
-                let events = detect_changes(&deployment, &state_manager).await;
+    // leader가 된 후에 불러와야 이전 leader가 마지막으로 저장한 상태를 이어받음
+    state_manager.restore().await;
+    {
+        let state_manager = state_manager.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(PERSIST_INTERVAL);
+            loop {
+                interval.tick().await;
+                state_manager.persist().await;
+            }
+        });
+    }
+
+    let detector_config = DetectorConfig::from_env();
+    if let Some(timeout) = detector_config.stall_timeout {
+        log::info!("Stall timeout: {}s", timeout.as_secs());
+    }
 
-                for event in events {
-                    notify(event, &notifier_config).await;
+    // 멈춘 배포는 watch 이벤트가 오지 않으므로 주기적으로 확인
+    {
+        let state_manager = state_manager.clone();
+        let dispatcher = dispatcher.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(STALL_CHECK_INTERVAL);
+            loop {
+                interval.tick().await;
+                for event in detect_stalled(&state_manager, &detector_config).await {
+                    dispatcher.dispatch(event).await;
                 }
+            }
+        });
+    }
 
-                Ok(())
+    let watches = async {
+        futures::try_join!(
+            watch_workload(deployments, &state_manager, &dispatcher, &health),
+            watch_workload(statefulsets, &state_manager, &dispatcher, &health),
+            watch_workload(daemonsets, &state_manager, &dispatcher, &health),
+        )
+        .map(|_| ())
+    };
+
+    match &leader_elector {
+        Some(elector) => {
+            tokio::select! {
+                result = watches => result,
+                _ = elector.keep_renewing() => {
+                    // 다른 replica가 알림을 이어받으므로 종료 후 standby로 재시작
+                    // 마지막 저장 이후의 변경을 기록해야 다음 leader가 이미 보낸 알림을 다시 보내지 않음
+                    log::error!("Exiting after losing leadership");
+                    state_manager.persist().await;
+                    std::process::exit(1);
+                }
             }
-        })
-        .await
-        .expect("watch failed");
+        }
+        None => watches.await,
+    }
 }
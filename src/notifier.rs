@@ -1,5 +1,36 @@
+mod blocks;
+mod card;
+mod discord;
+mod routing;
+mod slack;
+mod teams;
+mod webhook;
+
+use std::time::Duration;
+
 use crate::detector::DeploymentEvent;
-use serde::Serialize;
+use crate::diff::{ImageChange, TemplateChanges};
+use crate::http::client_from_env;
+use crate::retry::{RetryPolicy, SendError, with_retry};
+use crate::state::StateManager;
+use async_trait::async_trait;
+use blocks::SlackLink;
+use discord::DiscordNotifier;
+use routing::{Route, Router, parse_routes};
+use slack::{SlackNotifier, SlackTarget, UpdateLimiter};
+use teams::TeamsNotifier;
+use webhook::WebhookNotifier;
+
+const DEFAULT_SLACK_UPDATE_INTERVAL_SECONDS: u64 = 10;
+
+/// 알림 backend (Slack, Teams, Discord, webhook 등)
+///
+/// 실패 시 SendError로 재시도 여부를 알려주면 NotifierRegistry가 재시도
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    fn name(&self) -> &str;
+    async fn send(&self, event: &DeploymentEvent) -> Result<(), SendError>;
+}
 
 #[derive(Clone, Copy, Debug)]
 pub enum Language {
@@ -21,40 +52,145 @@ pub struct NotifierConfig {
     pub language: Language,
     pub slack_token: Option<String>,
     pub slack_channel: Option<String>,
+    pub slack_webhook_url: Option<String>, // bot token 대신 incoming webhook 사용
+    pub slack_threads: bool,               // 완료/실패 알림을 시작 메시지의 thread 답글로 전송
+    pub slack_reply_broadcast: bool,
+    pub slack_links: Vec<SlackLink>, // 메시지 하단 버튼 (대시보드 등)
+    pub cluster_name: Option<String>, // 메시지에 표시할 클러스터 이름
+    pub slack_update_interval: Option<Duration>, // 시작 메시지 진행 상황 갱신 주기 (None이면 비활성화)
+    pub teams_webhook_url: Option<String>,
+    pub discord_webhook_url: Option<String>,
+    pub webhook_urls: Vec<String>,
+    pub webhook_headers: Vec<(String, String)>,
+    pub webhook_secret: Option<String>, // 설정 시 HMAC-SHA256 서명
+    pub routes: Vec<Route>,
+    pub default_route: Option<Vec<String>>, // None이면 설정된 모든 backend
+    pub retry: RetryPolicy,
+    pub http_client: reqwest::Client,
 }
 
 impl NotifierConfig {
-    pub fn from_env() -> Self {
+    /// SLACK_LINKS, WEBHOOK_HEADERS, NOTIFY_ROUTES나 HTTP client 설정이 잘못된 경우 오류
+    pub fn from_env() -> Result<Self, String> {
         let language = std::env::var("LANGUAGE")
             .ok()
             .map(|s| Language::from_env(&s))
             .unwrap_or(Language::Korean);
 
-        let slack_token = std::env::var("SLACK_TOKEN").ok();
-        let slack_channel = std::env::var("SLACK_CHANNEL").ok();
+        // helm chart는 설정하지 않은 값도 빈 문자열로 전달
+        let slack_token = std::env::var("SLACK_TOKEN").ok().filter(|s| !s.is_empty());
+        let slack_channel = std::env::var("SLACK_CHANNEL")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let slack_webhook_url = std::env::var("SLACK_WEBHOOK_URL")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let slack_threads = std::env::var("SLACK_THREADS")
+            .map(|v| v != "false")
+            .unwrap_or(true);
+        let slack_reply_broadcast = std::env::var("SLACK_REPLY_BROADCAST")
+            .map(|v| v == "true")
+            .unwrap_or(false);
+        // 0이면 시작 메시지를 수정하지 않음
+        let slack_update_interval = std::env::var("SLACK_UPDATE_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_SLACK_UPDATE_INTERVAL_SECONDS);
+        let slack_update_interval =
+            (slack_update_interval > 0).then(|| Duration::from_secs(slack_update_interval));
+        // [{"text": "Grafana", "url": "https://grafana/...?var-namespace={namespace}"}] 형태의 JSON 배열
+        let slack_links = match std::env::var("SLACK_LINKS") {
+            Ok(json) => {
+                serde_json::from_str(&json).map_err(|e| format!("invalid SLACK_LINKS: {}", e))?
+            }
+            Err(_) => Vec::new(),
+        };
+        let cluster_name = std::env::var("CLUSTER_NAME").ok();
+        let teams_webhook_url = std::env::var("TEAMS_WEBHOOK_URL").ok();
+        let discord_webhook_url = std::env::var("DISCORD_WEBHOOK_URL").ok();
+
+        // 쉼표로 구분한 여러 URL
+        let webhook_urls = std::env::var("WEBHOOK_URLS")
+            .map(|s| {
+                s.split(',')
+                    .map(|u| u.trim().to_string())
+                    .filter(|u| !u.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        // {"Authorization": "Bearer ..."} 형태의 JSON 객체
+        let webhook_headers = match std::env::var("WEBHOOK_HEADERS") {
+            Ok(json) => serde_json::from_str::<std::collections::BTreeMap<String, String>>(&json)
+                .map_err(|e| format!("invalid WEBHOOK_HEADERS: {}", e))?
+                .into_iter()
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        let webhook_secret = std::env::var("WEBHOOK_SECRET").ok();
 
-        Self {
+        let routes = match std::env::var("NOTIFY_ROUTES") {
+            Ok(json) => parse_routes(&json)?,
+            Err(_) => Vec::new(),
+        };
+        // 쉼표로 구분한 목적지 (어떤 규칙과도 일치하지 않는 이벤트)
+        let default_route = std::env::var("NOTIFY_DEFAULT_ROUTE").ok().map(|s| {
+            s.split(',')
+                .map(|d| d.trim().to_string())
+                .filter(|d| !d.is_empty())
+                .collect()
+        });
+
+        Ok(Self {
             language,
             slack_token,
             slack_channel,
-        }
+            slack_webhook_url,
+            slack_threads,
+            slack_reply_broadcast,
+            slack_links,
+            cluster_name,
+            slack_update_interval,
+            teams_webhook_url,
+            discord_webhook_url,
+            webhook_urls,
+            webhook_headers,
+            webhook_secret,
+            routes,
+            default_route,
+            retry: RetryPolicy::from_env(),
+            http_client: client_from_env()?,
+        })
+    }
+}
+
+fn image_info(image_changes: &[ImageChange]) -> String {
+    if image_changes.is_empty() {
+        return String::new();
     }
+    let changes: Vec<String> = image_changes.iter().map(|c| c.describe()).collect();
+    format!(" (images: {})", changes.join(", "))
+}
 
-    pub fn has_slack_config(&self) -> bool {
-        self.slack_token.is_some() && self.slack_channel.is_some()
+fn changes_info(template_changes: &TemplateChanges) -> String {
+    if template_changes.is_empty() {
+        return String::new();
     }
+    format!(" (changes: {})", template_changes.summary().join("; "))
 }
 
 fn format_message(event: &DeploymentEvent, language: Language) -> String {
     match (event, language) {
         (
             DeploymentEvent::DeploymentStarted {
+                kind,
                 namespace,
                 name,
                 old_generation,
                 new_generation,
                 old_replicas,
                 new_replicas,
+                image_changes,
+                template_changes,
             },
             Language::Korean,
         ) => {
@@ -63,18 +199,28 @@ fn format_message(event: &DeploymentEvent, language: Language) -> String {
                 _ => String::new(),
             };
             format!(
-                "🚀 [배포 시작] {}/{}: 배포가 시작됩니다 (revision: {} -> {}){}",
-                namespace, name, old_generation, new_generation, replica_info
+                "🚀 [배포 시작] {} {}/{}: 배포가 시작됩니다 (revision: {} -> {}){}{}{}",
+                kind,
+                namespace,
+                name,
+                old_generation,
+                new_generation,
+                replica_info,
+                image_info(image_changes),
+                changes_info(template_changes)
             )
         }
         (
             DeploymentEvent::DeploymentStarted {
+                kind,
                 namespace,
                 name,
                 old_generation,
                 new_generation,
                 old_replicas,
                 new_replicas,
+                image_changes,
+                template_changes,
             },
             Language::English,
         ) => {
@@ -83,18 +229,125 @@ fn format_message(event: &DeploymentEvent, language: Language) -> String {
                 _ => String::new(),
             };
             format!(
-                "🚀 [Deploy Started] {}/{}: Deployment started (revision: {} -> {}){}",
-                namespace, name, old_generation, new_generation, replica_info
+                "🚀 [Deploy Started] {} {}/{}: Deployment started (revision: {} -> {}){}{}{}",
+                kind,
+                namespace,
+                name,
+                old_generation,
+                new_generation,
+                replica_info,
+                image_info(image_changes),
+                changes_info(template_changes)
+            )
+        }
+
+        (
+            DeploymentEvent::DeploymentRestarted {
+                kind,
+                namespace,
+                name,
+                old_generation,
+                new_generation,
+                restarted_at,
+            },
+            Language::Korean,
+        ) => {
+            format!(
+                "🔄 [재시작 시작] {} {}/{}: 재시작이 시작됩니다 (revision: {} -> {}, restartedAt: {})",
+                kind, namespace, name, old_generation, new_generation, restarted_at
+            )
+        }
+        (
+            DeploymentEvent::DeploymentRestarted {
+                kind,
+                namespace,
+                name,
+                old_generation,
+                new_generation,
+                restarted_at,
+            },
+            Language::English,
+        ) => {
+            format!(
+                "🔄 [Restart Started] {} {}/{}: Restart started (revision: {} -> {}, restartedAt: {})",
+                kind, namespace, name, old_generation, new_generation, restarted_at
+            )
+        }
+
+        (
+            DeploymentEvent::DeploymentRestartCompleted {
+                kind,
+                namespace,
+                name,
+                generation,
+                replicas,
+            },
+            Language::Korean,
+        ) => {
+            format!(
+                "✅ [재시작 완료] {} {}/{}: 재시작이 완료되었습니다 (revision: {}, replicas: {})",
+                kind, namespace, name, generation, replicas
+            )
+        }
+        (
+            DeploymentEvent::DeploymentRestartCompleted {
+                kind,
+                namespace,
+                name,
+                generation,
+                replicas,
+            },
+            Language::English,
+        ) => {
+            format!(
+                "✅ [Restart Completed] {} {}/{}: Restart completed (revision: {}, replicas: {})",
+                kind, namespace, name, generation, replicas
+            )
+        }
+
+        (
+            DeploymentEvent::DeploymentRolledBack {
+                kind,
+                namespace,
+                name,
+                old_generation,
+                new_generation,
+                rolled_back_to,
+            },
+            Language::Korean,
+        ) => {
+            format!(
+                "⏪ [롤백 시작] {} {}/{}: revision {}의 template으로 롤백합니다 (revision: {} -> {})",
+                kind, namespace, name, rolled_back_to, old_generation, new_generation
+            )
+        }
+        (
+            DeploymentEvent::DeploymentRolledBack {
+                kind,
+                namespace,
+                name,
+                old_generation,
+                new_generation,
+                rolled_back_to,
+            },
+            Language::English,
+        ) => {
+            format!(
+                "⏪ [Rollback Started] {} {}/{}: Rolling back to the template of revision {} (revision: {} -> {})",
+                kind, namespace, name, rolled_back_to, old_generation, new_generation
             )
         }
 
         (
             DeploymentEvent::DeploymentCompleted {
+                kind,
                 namespace,
                 name,
                 generation,
                 replicas,
                 replica_changed,
+                image_changes,
+                template_changes,
             },
             Language::Korean,
         ) => {
@@ -103,17 +356,26 @@ fn format_message(event: &DeploymentEvent, language: Language) -> String {
                 None => format!("replicas: {}", replicas),
             };
             format!(
-                "✅ [배포 완료] {}/{}: 배포가 완료되었습니다 (revision: {}, {})",
-                namespace, name, generation, replica_info
+                "✅ [배포 완료] {} {}/{}: 배포가 완료되었습니다 (revision: {}, {}){}{}",
+                kind,
+                namespace,
+                name,
+                generation,
+                replica_info,
+                image_info(image_changes),
+                changes_info(template_changes)
             )
         }
         (
             DeploymentEvent::DeploymentCompleted {
+                kind,
                 namespace,
                 name,
                 generation,
                 replicas,
                 replica_changed,
+                image_changes,
+                template_changes,
             },
             Language::English,
         ) => {
@@ -122,13 +384,20 @@ fn format_message(event: &DeploymentEvent, language: Language) -> String {
                 None => format!("replicas: {}", replicas),
             };
             format!(
-                "✅ [Deploy Completed] {}/{}: Deployment completed (revision: {}, {})",
-                namespace, name, generation, replica_info
+                "✅ [Deploy Completed] {} {}/{}: Deployment completed (revision: {}, {}){}{}",
+                kind,
+                namespace,
+                name,
+                generation,
+                replica_info,
+                image_info(image_changes),
+                changes_info(template_changes)
             )
         }
 
         (
             DeploymentEvent::ReplicaScaleStarted {
+                kind,
                 namespace,
                 name,
                 old_replicas,
@@ -138,18 +407,19 @@ fn format_message(event: &DeploymentEvent, language: Language) -> String {
         ) => {
             if new_replicas > old_replicas {
                 format!(
-                    "📈 [스케일 업] {}/{}: {} → {} replicas 증가",
-                    namespace, name, old_replicas, new_replicas
+                    "📈 [스케일 업] {} {}/{}: {} → {} replicas 증가",
+                    kind, namespace, name, old_replicas, new_replicas
                 )
             } else {
                 format!(
-                    "📉 [스케일 다운] {}/{}: {} → {} replicas 감소",
-                    namespace, name, old_replicas, new_replicas
+                    "📉 [스케일 다운] {} {}/{}: {} → {} replicas 감소",
+                    kind, namespace, name, old_replicas, new_replicas
                 )
             }
         }
         (
             DeploymentEvent::ReplicaScaleStarted {
+                kind,
                 namespace,
                 name,
                 old_replicas,
@@ -159,19 +429,20 @@ fn format_message(event: &DeploymentEvent, language: Language) -> String {
         ) => {
             if new_replicas > old_replicas {
                 format!(
-                    "📈 [Scale Up] {}/{}: {} → {} replicas",
-                    namespace, name, old_replicas, new_replicas
+                    "📈 [Scale Up] {} {}/{}: {} → {} replicas",
+                    kind, namespace, name, old_replicas, new_replicas
                 )
             } else {
                 format!(
-                    "📉 [Scale Down] {}/{}: {} → {} replicas",
-                    namespace, name, old_replicas, new_replicas
+                    "📉 [Scale Down] {} {}/{}: {} → {} replicas",
+                    kind, namespace, name, old_replicas, new_replicas
                 )
             }
         }
 
         (
             DeploymentEvent::ReplicaScaleCompleted {
+                kind,
                 namespace,
                 name,
                 replicas,
@@ -179,12 +450,13 @@ fn format_message(event: &DeploymentEvent, language: Language) -> String {
             Language::Korean,
         ) => {
             format!(
-                "✅ [스케일 완료] {}/{}: replica 수정이 완료되었습니다 (replicas: {})",
-                namespace, name, replicas
+                "✅ [스케일 완료] {} {}/{}: replica 수정이 완료되었습니다 (replicas: {})",
+                kind, namespace, name, replicas
             )
         }
         (
             DeploymentEvent::ReplicaScaleCompleted {
+                kind,
                 namespace,
                 name,
                 replicas,
@@ -192,230 +464,330 @@ fn format_message(event: &DeploymentEvent, language: Language) -> String {
             Language::English,
         ) => {
             format!(
-                "✅ [Scale Completed] {}/{}: Scaling completed (replicas: {})",
-                namespace, name, replicas
+                "✅ [Scale Completed] {} {}/{}: Scaling completed (replicas: {})",
+                kind, namespace, name, replicas
+            )
+        }
+
+        (
+            DeploymentEvent::DeploymentStalled {
+                kind,
+                namespace,
+                name,
+                generation,
+                elapsed_seconds,
+                replicas,
+                ready_replicas,
+                updated_replicas,
+            },
+            Language::Korean,
+        ) => {
+            format!(
+                "⚠️ [배포 지연] {} {}/{}: {}초가 지나도록 배포가 완료되지 않았습니다 (revision: {}, updated: {}/{}, ready: {}/{})",
+                kind,
+                namespace,
+                name,
+                elapsed_seconds,
+                generation,
+                updated_replicas,
+                replicas,
+                ready_replicas,
+                replicas
+            )
+        }
+        (
+            DeploymentEvent::DeploymentStalled {
+                kind,
+                namespace,
+                name,
+                generation,
+                elapsed_seconds,
+                replicas,
+                ready_replicas,
+                updated_replicas,
+            },
+            Language::English,
+        ) => {
+            format!(
+                "⚠️ [Deploy Stalled] {} {}/{}: Deployment not completed after {}s (revision: {}, updated: {}/{}, ready: {}/{})",
+                kind,
+                namespace,
+                name,
+                elapsed_seconds,
+                generation,
+                updated_replicas,
+                replicas,
+                ready_replicas,
+                replicas
+            )
+        }
+
+        (
+            DeploymentEvent::DeploymentFailed {
+                kind,
+                namespace,
+                name,
+                generation,
+                reason,
+                message,
+            },
+            Language::Korean,
+        ) => {
+            format!(
+                "❌ [배포 실패] {} {}/{}: 배포가 실패했습니다 (revision: {}, {}: {})",
+                kind, namespace, name, generation, reason, message
+            )
+        }
+        (
+            DeploymentEvent::DeploymentFailed {
+                kind,
+                namespace,
+                name,
+                generation,
+                reason,
+                message,
+            },
+            Language::English,
+        ) => {
+            format!(
+                "❌ [Deploy Failed] {} {}/{}: Deployment failed (revision: {}, {}: {})",
+                kind, namespace, name, generation, reason, message
+            )
+        }
+
+        (
+            DeploymentEvent::RolloutProgressed {
+                kind,
+                namespace,
+                name,
+                generation,
+                updated,
+                desired,
+            },
+            Language::Korean,
+        ) => {
+            format!(
+                "⏳ [배포 진행] {} {}/{}: {}/{} 노드 업데이트 완료 (revision: {})",
+                kind, namespace, name, updated, desired, generation
+            )
+        }
+        (
+            DeploymentEvent::RolloutProgressed {
+                kind,
+                namespace,
+                name,
+                generation,
+                updated,
+                desired,
+            },
+            Language::English,
+        ) => {
+            format!(
+                "⏳ [Deploy Progress] {} {}/{}: {}/{} nodes updated (revision: {})",
+                kind, namespace, name, updated, desired, generation
             )
         }
     }
 }
 
-#[derive(Serialize)]
-struct SlackMessage {
-    channel: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    text: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    attachments: Option<Vec<SlackAttachment>>,
+/// 설정된 알림 backend 목록
+///
+/// 새 backend는 Notifier를 구현하고 from_config에 등록하면 dispatch 코드를 고칠 필요 없음.
+/// backend마다 목적지 이름(slack, slack:<channel>, teams, discord, webhook)이 있고
+/// Router가 이벤트별로 보낼 목적지를 결정
+pub struct NotifierRegistry {
+    language: Language,
+    retry: RetryPolicy,
+    notifiers: Vec<(String, Box<dyn Notifier>)>,
+    router: Router,
+    slack_channel: Option<String>, // 기본 Slack 채널 (slack:<channel>과 같은 채널이면 중복 전송하지 않음)
+    state_manager: StateManager,
 }
 
-#[derive(Serialize)]
-struct SlackAttachment {
-    color: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    text: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    fields: Option<Vec<SlackField>>,
-}
+impl NotifierRegistry {
+    /// route에 설정되지 않은 backend가 있으면 오류
+    pub fn from_config(
+        config: &NotifierConfig,
+        state_manager: &StateManager,
+    ) -> Result<Self, String> {
+        let mut notifiers: Vec<(String, Box<dyn Notifier>)> = Vec::new();
+        // 모든 Slack 채널이 같은 token의 chat.update rate limit을 나눠 씀
+        let update_limiter = UpdateLimiter::default();
 
-#[derive(Serialize)]
-struct SlackField {
-    title: String,
-    value: String,
-    short: bool,
-}
+        // bot token이 있으면 우선 사용 (thread, 메시지 수정 지원)
+        let slack_target = match (
+            &config.slack_token,
+            &config.slack_channel,
+            &config.slack_webhook_url,
+        ) {
+            (Some(token), Some(channel), _) => Some(SlackTarget::Bot {
+                token: token.clone(),
+                channel: channel.clone(),
+            }),
+            (_, _, Some(url)) => Some(SlackTarget::Webhook { url: url.clone() }),
+            _ => None,
+        };
+        let slack_channel = match &slack_target {
+            Some(SlackTarget::Bot { channel, .. }) => Some(channel.clone()),
+            _ => None,
+        };
+        if let Some(target) = slack_target {
+            notifiers.push((
+                "slack".to_string(),
+                Box::new(SlackNotifier::new(
+                    config,
+                    target,
+                    state_manager.clone(),
+                    update_limiter.clone(),
+                )),
+            ));
+        }
 
-fn create_slack_attachment(event: &DeploymentEvent, language: Language) -> SlackAttachment {
-    let color = match event {
-        DeploymentEvent::DeploymentStarted { .. } => "warning".to_string(),
-        DeploymentEvent::DeploymentCompleted { .. } => "good".to_string(),
-        DeploymentEvent::ReplicaScaleStarted { .. } => "warning".to_string(),
-        DeploymentEvent::ReplicaScaleCompleted { .. } => "good".to_string(),
-    };
-
-    let (title, fields) = match event {
-        DeploymentEvent::DeploymentStarted {
-            namespace,
-            name,
-            old_generation,
-            new_generation,
-            old_replicas,
-            new_replicas,
-        } => {
-            let title = match language {
-                Language::Korean => "🚀 배포 시작",
-                Language::English => "🚀 Deploy Started",
-            };
-            let mut fields = vec![
-                SlackField {
-                    title: "Deployment".to_string(),
-                    value: format!("{}/{}", namespace, name),
-                    short: true,
-                },
-                SlackField {
-                    title: "Revision".to_string(),
-                    value: format!("{} → {}", old_generation, new_generation),
-                    short: true,
-                },
-            ];
-
-            if let (Some(old), Some(new)) = (old_replicas, new_replicas) {
-                fields.push(SlackField {
-                    title: "Replicas".to_string(),
-                    value: format!("{} → {}", old, new),
-                    short: true,
-                });
-            }
+        if let Some(url) = &config.teams_webhook_url {
+            notifiers.push((
+                "teams".to_string(),
+                Box::new(TeamsNotifier::new(
+                    config.http_client.clone(),
+                    url,
+                    config.language,
+                )),
+            ));
+        }
 
-            (title, fields)
+        if let Some(url) = &config.discord_webhook_url {
+            notifiers.push((
+                "discord".to_string(),
+                Box::new(DiscordNotifier::new(
+                    config.http_client.clone(),
+                    url,
+                    config.language,
+                )),
+            ));
         }
-        DeploymentEvent::DeploymentCompleted {
-            namespace,
-            name,
-            generation,
-            replicas,
-            replica_changed,
-        } => {
-            let title = match language {
-                Language::Korean => "✅ 배포 완료",
-                Language::English => "✅ Deploy Completed",
-            };
-            let replica_value = match replica_changed {
-                Some((old, new)) => format!("{} → {}", old, new),
-                None => replicas.to_string(),
-            };
-            let fields = vec![
-                SlackField {
-                    title: "Deployment".to_string(),
-                    value: format!("{}/{}", namespace, name),
-                    short: true,
-                },
-                SlackField {
-                    title: "Revision".to_string(),
-                    value: generation.to_string(),
-                    short: true,
-                },
-                SlackField {
-                    title: "Replicas".to_string(),
-                    value: replica_value,
-                    short: true,
-                },
-            ];
-            (title, fields)
+
+        for url in &config.webhook_urls {
+            notifiers.push((
+                "webhook".to_string(),
+                Box::new(WebhookNotifier::new(
+                    config.http_client.clone(),
+                    url,
+                    config.webhook_headers.clone(),
+                    config.webhook_secret.clone(),
+                    config.language,
+                )),
+            ));
         }
-        DeploymentEvent::ReplicaScaleStarted {
-            namespace,
-            name,
-            old_replicas,
-            new_replicas,
-        } => {
-            let title = if new_replicas > old_replicas {
-                match language {
-                    Language::Korean => "📈 스케일 업",
-                    Language::English => "📈 Scale Up",
-                }
-            } else {
-                match language {
-                    Language::Korean => "📉 스케일 다운",
-                    Language::English => "📉 Scale Down",
-                }
+
+        // route 없이 쓰던 설정은 그대로 모든 backend로 전송
+        let default_route = config.default_route.clone().unwrap_or_else(|| {
+            let mut destinations: Vec<String> = notifiers.iter().map(|(d, _)| d.clone()).collect();
+            destinations.dedup();
+            destinations
+        });
+        let router = Router::new(config.routes.clone(), default_route);
+
+        // route에서 지정한 Slack 채널마다 bot token으로 전송하는 backend 추가
+        let mut channels: Vec<&str> = router
+            .destinations()
+            .filter_map(|d| d.strip_prefix("slack:"))
+            .filter(|&c| Some(c) != slack_channel.as_deref())
+            .collect();
+        channels.sort_unstable();
+        channels.dedup();
+        for channel in channels {
+            let Some(token) = &config.slack_token else {
+                return Err(format!(
+                    "route destination slack:{} requires SLACK_TOKEN",
+                    channel
+                ));
             };
-            let fields = vec![
-                SlackField {
-                    title: "Deployment".to_string(),
-                    value: format!("{}/{}", namespace, name),
-                    short: true,
-                },
-                SlackField {
-                    title: "Replicas".to_string(),
-                    value: format!("{} → {}", old_replicas, new_replicas),
-                    short: true,
-                },
-            ];
-            (title, fields)
-        }
-        DeploymentEvent::ReplicaScaleCompleted {
-            namespace,
-            name,
-            replicas,
-        } => {
-            let title = match language {
-                Language::Korean => "✅ 스케일 완료",
-                Language::English => "✅ Scale Completed",
+            let target = SlackTarget::Bot {
+                token: token.clone(),
+                channel: channel.to_string(),
             };
-            let fields = vec![
-                SlackField {
-                    title: "Deployment".to_string(),
-                    value: format!("{}/{}", namespace, name),
-                    short: true,
-                },
-                SlackField {
-                    title: "Replicas".to_string(),
-                    value: replicas.to_string(),
-                    short: true,
-                },
-            ];
-            (title, fields)
+            notifiers.push((
+                format!("slack:{}", channel),
+                Box::new(SlackNotifier::new(
+                    config,
+                    target,
+                    state_manager.clone(),
+                    update_limiter.clone(),
+                )),
+            ));
         }
-    };
-
-    SlackAttachment {
-        color,
-        text: Some(title.to_string()),
-        fields: Some(fields),
-    }
-}
 
-async fn send_to_slack(
-    event: &DeploymentEvent,
-    language: Language,
-    token: &str,
-    channel: &str,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let client = reqwest::Client::new();
-    let attachment = create_slack_attachment(event, language);
-
-    let slack_message = SlackMessage {
-        channel: channel.to_string(),
-        text: None,
-        attachments: Some(vec![attachment]),
-    };
-
-    let response = client
-        .post("https://slack.com/api/chat.postMessage")
-        .header("Authorization", format!("Bearer {}", token))
-        .header("Content-Type", "application/json")
-        .json(&slack_message)
-        .send()
-        .await?;
-
-    // Slack API 응답 확인
-    let response_text = response.text().await?;
-
-    // Slack API는 200을 반환하지만 error 필드로 에러를 표시할 수 있음
-    let response_json: serde_json::Value = serde_json::from_str(&response_text)?;
-    if let Some(ok) = response_json.get("ok") {
-        if ok == &serde_json::Value::Bool(false) {
-            if let Some(error) = response_json.get("error") {
-                return Err(format!("Slack API error: {}", error).into());
+        for destination in router.destinations() {
+            let configured = notifiers.iter().any(|(d, _)| d == destination)
+                || destination.strip_prefix("slack:") == slack_channel.as_deref();
+            if !configured {
+                return Err(format!(
+                    "route destination {} is not configured",
+                    destination
+                ));
             }
         }
-    }
 
-    Ok(())
-}
+        Ok(Self {
+            language: config.language,
+            retry: config.retry.clone(),
+            notifiers,
+            router,
+            slack_channel,
+            state_manager: state_manager.clone(),
+        })
+    }
 
-pub async fn notify(event: DeploymentEvent, config: &NotifierConfig) {
-    let message = format_message(&event, config.language);
+    pub fn names(&self) -> Vec<&str> {
+        self.notifiers.iter().map(|(_, n)| n.name()).collect()
+    }
 
-    // stdout 출력
-    log::info!("{}", message);
+    // 이벤트를 보낼 backend 목적지 (기본 Slack 채널을 가리키는 slack:<channel>은 slack으로)
+    async fn destinations(&self, event: &DeploymentEvent) -> Vec<String> {
+        let labels = self
+            .state_manager
+            .get(&event.key())
+            .await
+            .map(|s| s.labels)
+            .unwrap_or_default();
 
-    // Slack 전송 (Block Kit 사용)
-    if config.has_slack_config() {
-        if let (Some(token), Some(channel)) = (&config.slack_token, &config.slack_channel) {
-            if let Err(e) = send_to_slack(&event, config.language, token, channel).await {
-                eprintln!("Failed to send Slack notification: {}", e);
+        let mut destinations: Vec<String> = Vec::new();
+        for destination in self.router.route(event, &labels) {
+            let destination = match destination.strip_prefix("slack:") {
+                Some(channel) if Some(channel) == self.slack_channel.as_deref() => "slack",
+                _ => destination,
+            };
+            if !destinations.iter().any(|d| d == destination) {
+                destinations.push(destination.to_string());
             }
         }
+        destinations
+    }
+
+    /// stdout에 기록하고 route에 따라 선택된 backend로 동시에 전송 (backend별로 재시도)
+    ///
+    /// 하나라도 전송에 실패하면 false
+    pub async fn notify(&self, event: &DeploymentEvent) -> bool {
+        let message = format_message(event, self.language);
+
+        // stdout 출력
+        log::info!("{}", message);
+
+        let destinations = self.destinations(event).await;
+        let sends = self
+            .notifiers
+            .iter()
+            .filter(|(destination, _)| destinations.contains(destination))
+            .map(|(_, notifier)| async move {
+                let result =
+                    with_retry(&self.retry, notifier.name(), || notifier.send(event)).await;
+                if let Err(e) = &result {
+                    log::error!("Failed to send {} notification: {}", notifier.name(), e);
+                }
+                result.is_ok()
+            });
+        futures::future::join_all(sends)
+            .await
+            .into_iter()
+            .all(|delivered| delivered)
     }
 }
@@ -0,0 +1,125 @@
+use k8s_openapi::jiff::Timestamp;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::card::Card;
+use crate::detector::DeploymentEvent;
+
+// Block Kit 제한
+const SECTION_FIELDS_LIMIT: usize = 10;
+const TEXT_LIMIT: usize = 3000;
+
+// 진행 상황을 표시하는 block (chat.update에서 교체)
+const PROGRESS_BLOCK_ID: &str = "progress";
+
+/// 메시지 하단에 버튼으로 표시할 링크
+///
+/// url의 {kind}, {namespace}, {name}은 이벤트의 워크로드로 치환
+#[derive(Clone, Debug, Deserialize)]
+pub struct SlackLink {
+    pub text: String,
+    pub url: String,
+}
+
+/// header, section fields, 버튼, context(시각, 클러스터)로 구성된 Block Kit 메시지
+pub fn render_blocks(
+    card: &Card,
+    event: &DeploymentEvent,
+    links: &[SlackLink],
+    cluster_name: Option<&str>,
+) -> Vec<Value> {
+    let mut blocks = vec![json!({
+        "type": "header",
+        "text": { "type": "plain_text", "text": card.title, "emoji": true },
+    })];
+
+    // 짧은 항목은 2열 fields로, 이미지/변경 목록처럼 긴 항목은 별도 section으로 표시
+    let short_fields: Vec<Value> = card
+        .fields
+        .iter()
+        .filter(|f| f.short)
+        .map(|f| mrkdwn(&format!("*{}*\n{}", f.title, f.value)))
+        .collect();
+    for fields in short_fields.chunks(SECTION_FIELDS_LIMIT) {
+        blocks.push(json!({ "type": "section", "fields": fields }));
+    }
+    for field in card.fields.iter().filter(|f| !f.short) {
+        blocks.push(json!({
+            "type": "section",
+            "text": mrkdwn(&format!("*{}*\n{}", field.title, field.value)),
+        }));
+    }
+
+    if !links.is_empty() {
+        let (kind, namespace, name) = event.target();
+        let buttons: Vec<Value> = links
+            .iter()
+            .map(|link| {
+                let url = link
+                    .url
+                    .replace("{kind}", kind)
+                    .replace("{namespace}", namespace)
+                    .replace("{name}", name);
+                json!({
+                    "type": "button",
+                    "text": { "type": "plain_text", "text": link.text },
+                    "url": url,
+                })
+            })
+            .collect();
+        blocks.push(json!({ "type": "actions", "elements": buttons }));
+    }
+
+    // Slack이 읽는 사람의 시간대로 표시
+    let now = Timestamp::now();
+    let mut context = vec![mrkdwn(&format!(
+        "<!date^{}^{{date_short_pretty}} {{time_secs}}|{}>",
+        now.as_second(),
+        now
+    ))];
+    if let Some(cluster_name) = cluster_name {
+        context.push(mrkdwn(&format!("Cluster: *{}*", cluster_name)));
+    }
+    blocks.push(json!({ "type": "context", "elements": context }));
+
+    blocks
+}
+
+/// 진행 상황 block을 넣거나 교체하고, title이 주어지면 header도 변경
+///
+/// 이전 버전이 저장한 메시지처럼 blocks 형식이 아니면 None
+pub fn with_progress(blocks: &Value, text: &str, title: Option<&str>) -> Option<Value> {
+    let mut blocks = blocks.as_array()?.clone();
+
+    blocks.retain(|b| b.get("block_id").and_then(|id| id.as_str()) != Some(PROGRESS_BLOCK_ID));
+    let progress = json!({
+        "type": "section",
+        "block_id": PROGRESS_BLOCK_ID,
+        "text": mrkdwn(&format!("*Progress*\n{}", text)),
+    });
+    // context(시각, 클러스터)는 항상 마지막에 유지
+    let position = match blocks.last().and_then(|b| b.get("type")) {
+        Some(t) if t == "context" => blocks.len() - 1,
+        _ => blocks.len(),
+    };
+    blocks.insert(position, progress);
+
+    if let Some(title) = title
+        && let Some(header) = blocks.iter_mut().find(|b| b["type"] == "header")
+    {
+        header["text"]["text"] = title.into();
+    }
+
+    Some(Value::Array(blocks))
+}
+
+fn mrkdwn(text: &str) -> Value {
+    let text = if text.chars().count() > TEXT_LIMIT {
+        let mut truncated: String = text.chars().take(TEXT_LIMIT - 1).collect();
+        truncated.push('…');
+        truncated
+    } else {
+        text.to_string()
+    };
+    json!({ "type": "mrkdwn", "text": text })
+}
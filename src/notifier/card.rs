@@ -0,0 +1,411 @@
+use super::Language;
+use crate::detector::DeploymentEvent;
+use crate::diff::{ImageChange, TemplateChanges};
+
+/// 이벤트 종류별 색상 분류 (backend마다 자기 색으로 변환)
+#[derive(Clone, Copy, Debug)]
+pub enum Tone {
+    InProgress, // 시작, 재시작, 롤백, 스케일 시작
+    Success,
+    Warning, // 지연
+    Failure,
+    Info, // 진행 상황
+}
+
+/// Slack, Teams, Discord 메시지가 공통으로 보여주는 내용
+pub struct Card {
+    pub tone: Tone,
+    pub title: &'static str,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Clone)]
+pub struct Field {
+    pub title: String,
+    pub value: String,
+    pub short: bool, // 다른 short field와 나란히 표시
+}
+
+fn image_field(image_changes: &[ImageChange]) -> Option<Field> {
+    if image_changes.is_empty() {
+        return None;
+    }
+    let changes: Vec<String> = image_changes.iter().map(|c| c.describe()).collect();
+    Some(Field {
+        title: "Images".to_string(),
+        value: changes.join("\n"),
+        short: false,
+    })
+}
+
+fn changes_field(template_changes: &TemplateChanges) -> Option<Field> {
+    if template_changes.is_empty() {
+        return None;
+    }
+    Some(Field {
+        title: "Changes".to_string(),
+        value: template_changes.summary().join("\n"),
+        short: false,
+    })
+}
+
+pub fn build_card(event: &DeploymentEvent, language: Language) -> Card {
+    let tone = match event {
+        DeploymentEvent::DeploymentStarted { .. } => Tone::InProgress,
+        DeploymentEvent::DeploymentRestarted { .. } => Tone::InProgress,
+        DeploymentEvent::DeploymentRestartCompleted { .. } => Tone::Success,
+        DeploymentEvent::DeploymentRolledBack { .. } => Tone::InProgress,
+        DeploymentEvent::DeploymentCompleted { .. } => Tone::Success,
+        DeploymentEvent::ReplicaScaleStarted { .. } => Tone::InProgress,
+        DeploymentEvent::ReplicaScaleCompleted { .. } => Tone::Success,
+        DeploymentEvent::DeploymentStalled { .. } => Tone::Warning,
+        DeploymentEvent::DeploymentFailed { .. } => Tone::Failure,
+        DeploymentEvent::RolloutProgressed { .. } => Tone::Info,
+    };
+
+    let (title, fields) = match event {
+        DeploymentEvent::DeploymentStarted {
+            kind,
+            namespace,
+            name,
+            old_generation,
+            new_generation,
+            old_replicas,
+            new_replicas,
+            image_changes,
+            template_changes,
+        } => {
+            let title = match language {
+                Language::Korean => "🚀 배포 시작",
+                Language::English => "🚀 Deploy Started",
+            };
+            let mut fields = vec![
+                Field {
+                    title: kind.clone(),
+                    value: format!("{}/{}", namespace, name),
+                    short: true,
+                },
+                Field {
+                    title: "Revision".to_string(),
+                    value: format!("{} → {}", old_generation, new_generation),
+                    short: true,
+                },
+            ];
+
+            if let (Some(old), Some(new)) = (old_replicas, new_replicas) {
+                fields.push(Field {
+                    title: "Replicas".to_string(),
+                    value: format!("{} → {}", old, new),
+                    short: true,
+                });
+            }
+            fields.extend(image_field(image_changes));
+            fields.extend(changes_field(template_changes));
+
+            (title, fields)
+        }
+        DeploymentEvent::DeploymentRestarted {
+            kind,
+            namespace,
+            name,
+            old_generation,
+            new_generation,
+            restarted_at,
+        } => {
+            let title = match language {
+                Language::Korean => "🔄 재시작 시작",
+                Language::English => "🔄 Restart Started",
+            };
+            let fields = vec![
+                Field {
+                    title: kind.clone(),
+                    value: format!("{}/{}", namespace, name),
+                    short: true,
+                },
+                Field {
+                    title: "Revision".to_string(),
+                    value: format!("{} → {}", old_generation, new_generation),
+                    short: true,
+                },
+                Field {
+                    title: "Restarted At".to_string(),
+                    value: restarted_at.clone(),
+                    short: true,
+                },
+            ];
+            (title, fields)
+        }
+        DeploymentEvent::DeploymentRestartCompleted {
+            kind,
+            namespace,
+            name,
+            generation,
+            replicas,
+        } => {
+            let title = match language {
+                Language::Korean => "✅ 재시작 완료",
+                Language::English => "✅ Restart Completed",
+            };
+            let fields = vec![
+                Field {
+                    title: kind.clone(),
+                    value: format!("{}/{}", namespace, name),
+                    short: true,
+                },
+                Field {
+                    title: "Revision".to_string(),
+                    value: generation.to_string(),
+                    short: true,
+                },
+                Field {
+                    title: "Replicas".to_string(),
+                    value: replicas.to_string(),
+                    short: true,
+                },
+            ];
+            (title, fields)
+        }
+        DeploymentEvent::DeploymentRolledBack {
+            kind,
+            namespace,
+            name,
+            old_generation,
+            new_generation,
+            rolled_back_to,
+        } => {
+            let title = match language {
+                Language::Korean => "⏪ 롤백 시작",
+                Language::English => "⏪ Rollback Started",
+            };
+            let fields = vec![
+                Field {
+                    title: kind.clone(),
+                    value: format!("{}/{}", namespace, name),
+                    short: true,
+                },
+                Field {
+                    title: "Revision".to_string(),
+                    value: format!("{} → {}", old_generation, new_generation),
+                    short: true,
+                },
+                Field {
+                    title: "Rolled Back To".to_string(),
+                    value: rolled_back_to.to_string(),
+                    short: true,
+                },
+            ];
+            (title, fields)
+        }
+        DeploymentEvent::DeploymentCompleted {
+            kind,
+            namespace,
+            name,
+            generation,
+            replicas,
+            replica_changed,
+            image_changes,
+            template_changes,
+        } => {
+            let title = match language {
+                Language::Korean => "✅ 배포 완료",
+                Language::English => "✅ Deploy Completed",
+            };
+            let replica_value = match replica_changed {
+                Some((old, new)) => format!("{} → {}", old, new),
+                None => replicas.to_string(),
+            };
+            let mut fields = vec![
+                Field {
+                    title: kind.clone(),
+                    value: format!("{}/{}", namespace, name),
+                    short: true,
+                },
+                Field {
+                    title: "Revision".to_string(),
+                    value: generation.to_string(),
+                    short: true,
+                },
+                Field {
+                    title: "Replicas".to_string(),
+                    value: replica_value,
+                    short: true,
+                },
+            ];
+            fields.extend(image_field(image_changes));
+            fields.extend(changes_field(template_changes));
+            (title, fields)
+        }
+        DeploymentEvent::ReplicaScaleStarted {
+            kind,
+            namespace,
+            name,
+            old_replicas,
+            new_replicas,
+        } => {
+            let title = if new_replicas > old_replicas {
+                match language {
+                    Language::Korean => "📈 스케일 업",
+                    Language::English => "📈 Scale Up",
+                }
+            } else {
+                match language {
+                    Language::Korean => "📉 스케일 다운",
+                    Language::English => "📉 Scale Down",
+                }
+            };
+            let fields = vec![
+                Field {
+                    title: kind.clone(),
+                    value: format!("{}/{}", namespace, name),
+                    short: true,
+                },
+                Field {
+                    title: "Replicas".to_string(),
+                    value: format!("{} → {}", old_replicas, new_replicas),
+                    short: true,
+                },
+            ];
+            (title, fields)
+        }
+        DeploymentEvent::ReplicaScaleCompleted {
+            kind,
+            namespace,
+            name,
+            replicas,
+        } => {
+            let title = match language {
+                Language::Korean => "✅ 스케일 완료",
+                Language::English => "✅ Scale Completed",
+            };
+            let fields = vec![
+                Field {
+                    title: kind.clone(),
+                    value: format!("{}/{}", namespace, name),
+                    short: true,
+                },
+                Field {
+                    title: "Replicas".to_string(),
+                    value: replicas.to_string(),
+                    short: true,
+                },
+            ];
+            (title, fields)
+        }
+        DeploymentEvent::DeploymentStalled {
+            kind,
+            namespace,
+            name,
+            generation,
+            elapsed_seconds,
+            replicas,
+            ready_replicas,
+            updated_replicas,
+        } => {
+            let title = match language {
+                Language::Korean => "⚠️ 배포 지연",
+                Language::English => "⚠️ Deploy Stalled",
+            };
+            let fields = vec![
+                Field {
+                    title: kind.clone(),
+                    value: format!("{}/{}", namespace, name),
+                    short: true,
+                },
+                Field {
+                    title: "Revision".to_string(),
+                    value: generation.to_string(),
+                    short: true,
+                },
+                Field {
+                    title: "Elapsed".to_string(),
+                    value: format!("{}s", elapsed_seconds),
+                    short: true,
+                },
+                Field {
+                    title: "Updated".to_string(),
+                    value: format!("{}/{}", updated_replicas, replicas),
+                    short: true,
+                },
+                Field {
+                    title: "Ready".to_string(),
+                    value: format!("{}/{}", ready_replicas, replicas),
+                    short: true,
+                },
+            ];
+            (title, fields)
+        }
+        DeploymentEvent::DeploymentFailed {
+            kind,
+            namespace,
+            name,
+            generation,
+            reason,
+            message,
+        } => {
+            let title = match language {
+                Language::Korean => "❌ 배포 실패",
+                Language::English => "❌ Deploy Failed",
+            };
+            let fields = vec![
+                Field {
+                    title: kind.clone(),
+                    value: format!("{}/{}", namespace, name),
+                    short: true,
+                },
+                Field {
+                    title: "Revision".to_string(),
+                    value: generation.to_string(),
+                    short: true,
+                },
+                Field {
+                    title: "Reason".to_string(),
+                    value: reason.clone(),
+                    short: true,
+                },
+                Field {
+                    title: "Message".to_string(),
+                    value: message.clone(),
+                    short: false,
+                },
+            ];
+            (title, fields)
+        }
+        DeploymentEvent::RolloutProgressed {
+            kind,
+            namespace,
+            name,
+            generation,
+            updated,
+            desired,
+        } => {
+            let title = match language {
+                Language::Korean => "⏳ 배포 진행",
+                Language::English => "⏳ Deploy Progress",
+            };
+            let fields = vec![
+                Field {
+                    title: kind.clone(),
+                    value: format!("{}/{}", namespace, name),
+                    short: true,
+                },
+                Field {
+                    title: "Revision".to_string(),
+                    value: generation.to_string(),
+                    short: true,
+                },
+                Field {
+                    title: "Nodes".to_string(),
+                    value: format!("{}/{}", updated, desired),
+                    short: true,
+                },
+            ];
+            (title, fields)
+        }
+    };
+
+    Card {
+        tone,
+        title,
+        fields,
+    }
+}
@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use k8s_openapi::jiff::Timestamp;
+use serde::Serialize;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use super::card::{Tone, build_card};
+use super::{Language, Notifier};
+use crate::detector::DeploymentEvent;
+use crate::retry::{SendError, retry_after};
+
+// Discord embed field 길이 제한
+const FIELD_NAME_LIMIT: usize = 256;
+const FIELD_VALUE_LIMIT: usize = 1024;
+
+/// Discord webhook으로 embed 전송
+///
+/// webhook마다 rate limit bucket이 있으므로 응답의 X-RateLimit-* 헤더를 보고
+/// 남은 요청이 없으면 reset될 때까지 다음 전송을 미룸
+pub struct DiscordNotifier {
+    client: reqwest::Client,
+    webhook_url: String,
+    language: Language,
+    blocked_until: Mutex<Option<Instant>>,
+}
+
+impl DiscordNotifier {
+    pub fn new(client: reqwest::Client, webhook_url: &str, language: Language) -> Self {
+        Self {
+            client,
+            webhook_url: webhook_url.to_string(),
+            language,
+            blocked_until: Mutex::new(None),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DiscordMessage {
+    embeds: Vec<DiscordEmbed>,
+}
+
+#[derive(Serialize)]
+struct DiscordEmbed {
+    title: String,
+    color: u32,
+    fields: Vec<DiscordField>,
+    timestamp: String,
+}
+
+#[derive(Serialize)]
+struct DiscordField {
+    name: String,
+    value: String,
+    inline: bool,
+}
+
+fn embed_color(tone: Tone) -> u32 {
+    match tone {
+        Tone::InProgress => 0xDAA038,
+        Tone::Success => 0x2EB67D,
+        Tone::Warning => 0xE8912D,
+        Tone::Failure => 0xD00000,
+        Tone::Info => 0x439FE0,
+    }
+}
+
+fn truncate(value: &str, limit: usize) -> String {
+    if value.chars().count() <= limit {
+        return value.to_string();
+    }
+    let mut truncated: String = value.chars().take(limit - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+fn header_seconds(headers: &reqwest::header::HeaderMap, name: &str) -> Option<Duration> {
+    headers
+        .get(name)?
+        .to_str()
+        .ok()?
+        .parse::<f64>()
+        .ok()
+        .map(Duration::from_secs_f64)
+}
+
+#[async_trait]
+impl Notifier for DiscordNotifier {
+    fn name(&self) -> &str {
+        "Discord"
+    }
+
+    async fn send(&self, event: &DeploymentEvent) -> Result<(), SendError> {
+        // 이전 응답에서 bucket이 소진되었으면 reset까지 대기
+        let mut blocked_until = self.blocked_until.lock().await;
+        if let Some(until) = blocked_until.take() {
+            tokio::time::sleep_until(until).await;
+        }
+
+        let card = build_card(event, self.language);
+        let message = DiscordMessage {
+            embeds: vec![DiscordEmbed {
+                title: card.title.to_string(),
+                color: embed_color(card.tone),
+                fields: card
+                    .fields
+                    .iter()
+                    .map(|f| DiscordField {
+                        name: truncate(&f.title, FIELD_NAME_LIMIT),
+                        value: truncate(&f.value, FIELD_VALUE_LIMIT),
+                        inline: f.short,
+                    })
+                    .collect(),
+                timestamp: Timestamp::now().to_string(),
+            }],
+        };
+
+        let response = self
+            .client
+            .post(&self.webhook_url)
+            .json(&message)
+            .send()
+            .await?;
+
+        let headers = response.headers();
+        let reset_after = header_seconds(headers, "X-RateLimit-Reset-After");
+        let remaining = headers
+            .get("X-RateLimit-Remaining")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u32>().ok());
+        if remaining == Some(0)
+            && let Some(reset_after) = reset_after
+        {
+            *blocked_until = Some(Instant::now() + reset_after);
+        }
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            // global rate limit은 Retry-After, bucket rate limit은 X-RateLimit-Reset-After
+            return Err(SendError::Retryable {
+                message: "Discord webhook rate limited".to_string(),
+                retry_after: retry_after(headers).or(reset_after),
+            });
+        }
+        if status.is_server_error() {
+            return Err(SendError::retryable(format!(
+                "Discord webhook returned {}",
+                status
+            )));
+        }
+        if !status.is_success() {
+            let response_text = response.text().await?;
+            return Err(SendError::Permanent(format!(
+                "Discord webhook returned {}: {}",
+                status, response_text
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,365 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::detector::DeploymentEvent;
+
+/// NOTIFY_ROUTES의 규칙 하나
+///
+/// 비어 있는 조건은 모든 이벤트와 일치
+#[derive(Deserialize)]
+struct RawRoute {
+    #[serde(default)]
+    namespaces: Vec<String>, // namespace glob (*, ?)
+    #[serde(default)]
+    selector: String, // "team=payments,tier!=batch,canary,!legacy"
+    #[serde(default)]
+    kinds: Vec<String>,
+    #[serde(default)]
+    events: Vec<String>, // DeploymentEvent::event_type 이름
+    to: Vec<String>, // 비어 있으면 알림을 보내지 않음
+    #[serde(default, rename = "continue")]
+    continue_matching: bool, // 일치해도 다음 규칙을 계속 확인
+}
+
+#[derive(Clone, Debug)]
+enum Requirement {
+    Equals(String, String),
+    NotEquals(String, String),
+    Exists(String),
+    NotExists(String),
+}
+
+impl Requirement {
+    fn matches(&self, labels: &BTreeMap<String, String>) -> bool {
+        match self {
+            Requirement::Equals(key, value) => labels.get(key) == Some(value),
+            Requirement::NotEquals(key, value) => labels.get(key) != Some(value),
+            Requirement::Exists(key) => labels.contains_key(key),
+            Requirement::NotExists(key) => !labels.contains_key(key),
+        }
+    }
+}
+
+// kubectl의 equality-based selector와 같은 형식
+fn parse_selector(selector: &str) -> Result<Vec<Requirement>, String> {
+    selector
+        .split(',')
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(|requirement| {
+            let parsed = if let Some((key, value)) = requirement.split_once("!=") {
+                Requirement::NotEquals(key.trim().to_string(), value.trim().to_string())
+            } else if let Some((key, value)) = requirement
+                .split_once("==")
+                .or_else(|| requirement.split_once('='))
+            {
+                Requirement::Equals(key.trim().to_string(), value.trim().to_string())
+            } else if let Some(key) = requirement.strip_prefix('!') {
+                Requirement::NotExists(key.trim().to_string())
+            } else {
+                Requirement::Exists(requirement.to_string())
+            };
+            match &parsed {
+                Requirement::Equals(key, _)
+                | Requirement::NotEquals(key, _)
+                | Requirement::Exists(key)
+                | Requirement::NotExists(key)
+                    if key.is_empty() =>
+                {
+                    Err(format!("invalid label selector: {}", selector))
+                }
+                _ => Ok(parsed),
+            }
+        })
+        .collect()
+}
+
+// *는 0개 이상, ?는 정확히 1개의 문자와 일치
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // 마지막으로 만난 *의 위치와 그 *가 대신한 text의 끝
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, t));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[derive(Clone, Debug)]
+pub struct Route {
+    namespaces: Vec<String>,
+    selector: Vec<Requirement>,
+    kinds: Vec<String>,
+    events: Vec<String>,
+    to: Vec<String>,
+    continue_matching: bool,
+}
+
+impl Route {
+    fn matches(&self, event: &DeploymentEvent, labels: &BTreeMap<String, String>) -> bool {
+        let (kind, namespace, _) = event.target();
+
+        (self.namespaces.is_empty() || self.namespaces.iter().any(|p| glob_match(p, namespace)))
+            && (self.kinds.is_empty() || self.kinds.iter().any(|k| k.eq_ignore_ascii_case(kind)))
+            && (self.events.is_empty() || self.events.iter().any(|e| e == event.event_type()))
+            && self.selector.iter().all(|r| r.matches(labels))
+    }
+}
+
+/// NOTIFY_ROUTES JSON 배열 파싱
+///
+/// [{"namespaces": ["payments-*"], "selector": "team=payments", "to": ["slack:C0123"]}]
+pub fn parse_routes(json: &str) -> Result<Vec<Route>, String> {
+    let raw: Vec<RawRoute> =
+        serde_json::from_str(json).map_err(|e| format!("invalid NOTIFY_ROUTES: {}", e))?;
+
+    raw.into_iter()
+        .map(|r| {
+            Ok(Route {
+                namespaces: r.namespaces,
+                selector: parse_selector(&r.selector)
+                    .map_err(|e| format!("invalid NOTIFY_ROUTES: {}", e))?,
+                kinds: r.kinds,
+                events: r.events,
+                to: r.to,
+                continue_matching: r.continue_matching,
+            })
+        })
+        .collect()
+}
+
+/// 이벤트를 보낼 목적지 이름을 결정
+///
+/// 규칙을 순서대로 확인해 처음 일치한 규칙의 목적지로 보내고 (continue면 이어서 확인),
+/// 일치하는 규칙이 없으면 기본 route 사용
+pub struct Router {
+    routes: Vec<Route>,
+    default_route: Vec<String>,
+}
+
+impl Router {
+    pub fn new(routes: Vec<Route>, default_route: Vec<String>) -> Self {
+        Self {
+            routes,
+            default_route,
+        }
+    }
+
+    /// 규칙과 기본 route에 쓰인 모든 목적지
+    pub fn destinations(&self) -> impl Iterator<Item = &str> {
+        self.routes
+            .iter()
+            .flat_map(|r| r.to.iter())
+            .chain(self.default_route.iter())
+            .map(String::as_str)
+    }
+
+    pub fn route(&self, event: &DeploymentEvent, labels: &BTreeMap<String, String>) -> Vec<&str> {
+        let mut destinations: Vec<&str> = Vec::new();
+        let mut matched = false;
+
+        for route in &self.routes {
+            if !route.matches(event, labels) {
+                continue;
+            }
+            matched = true;
+            for to in &route.to {
+                if !destinations.contains(&to.as_str()) {
+                    destinations.push(to);
+                }
+            }
+            if !route.continue_matching {
+                break;
+            }
+        }
+
+        if !matched {
+            destinations.extend(self.default_route.iter().map(String::as_str));
+        }
+        destinations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(kind: &str, namespace: &str) -> DeploymentEvent {
+        DeploymentEvent::DeploymentFailed {
+            kind: kind.to_string(),
+            namespace: namespace.to_string(),
+            name: "api".to_string(),
+            generation: 2,
+            reason: "ProgressDeadlineExceeded".to_string(),
+            message: String::new(),
+        }
+    }
+
+    fn labels(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn router(json: &str, default_route: &[&str]) -> Router {
+        Router::new(
+            parse_routes(json).unwrap(),
+            default_route.iter().map(|d| d.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn glob_matches_star_and_question_mark() {
+        assert!(glob_match("payments-*", "payments-prod"));
+        assert!(glob_match("payments-*", "payments-"));
+        assert!(!glob_match("payments-*", "payment-prod"));
+        assert!(glob_match("team-?", "team-a"));
+        assert!(!glob_match("team-?", "team-ab"));
+        assert!(!glob_match("team-?", "team-"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("prod", "prod"));
+        assert!(!glob_match("prod", "production"));
+    }
+
+    #[test]
+    fn glob_backtracks_after_partial_match() {
+        // 첫 번째 "-prod"에서 일치하지 않으면 *가 더 많은 문자를 차지해야 함
+        assert!(glob_match("*-prod", "api-prod-prod"));
+        assert!(glob_match("a*b*c", "axxbyybzzc"));
+        assert!(glob_match("*a?c*", "xxabxabcyy"));
+        assert!(!glob_match("a*b*c", "axxbyybzz"));
+        assert!(!glob_match("*-prod", "api-prod-staging"));
+    }
+
+    #[test]
+    fn selector_parses_each_operator() {
+        let requirements =
+            parse_selector("team=payments, tier!=batch,canary,!legacy,env==prod").unwrap();
+        assert!(
+            matches!(&requirements[0], Requirement::Equals(k, v) if k == "team" && v == "payments")
+        );
+        assert!(
+            matches!(&requirements[1], Requirement::NotEquals(k, v) if k == "tier" && v == "batch")
+        );
+        assert!(matches!(&requirements[2], Requirement::Exists(k) if k == "canary"));
+        assert!(matches!(&requirements[3], Requirement::NotExists(k) if k == "legacy"));
+        assert!(matches!(&requirements[4], Requirement::Equals(k, v) if k == "env" && v == "prod"));
+
+        assert!(parse_selector("").unwrap().is_empty());
+        assert!(parse_selector("=payments").is_err());
+        assert!(parse_selector("!").is_err());
+    }
+
+    #[test]
+    fn selector_matches_labels() {
+        let requirements = parse_selector("team=payments,tier!=batch,!legacy").unwrap();
+        let matches = |l: &BTreeMap<String, String>| requirements.iter().all(|r| r.matches(l));
+
+        assert!(matches(&labels(&[("team", "payments")])));
+        assert!(matches(&labels(&[("team", "payments"), ("tier", "web")])));
+        assert!(!matches(&labels(&[
+            ("team", "payments"),
+            ("tier", "batch")
+        ])));
+        assert!(!matches(&labels(&[("team", "payments"), ("legacy", "")])));
+        assert!(!matches(&labels(&[("team", "search")])));
+        assert!(!matches(&labels(&[])));
+    }
+
+    #[test]
+    fn first_matching_route_wins() {
+        let router = router(
+            r#"[
+                {"namespaces": ["payments-*"], "to": ["slack:C1"]},
+                {"namespaces": ["*"], "to": ["slack:C2"]}
+            ]"#,
+            &["slack"],
+        );
+
+        assert_eq!(
+            router.route(&event("Deployment", "payments-prod"), &labels(&[])),
+            vec!["slack:C1"]
+        );
+        assert_eq!(
+            router.route(&event("Deployment", "search"), &labels(&[])),
+            vec!["slack:C2"]
+        );
+    }
+
+    #[test]
+    fn continue_fans_out_without_duplicates() {
+        let router = router(
+            r#"[
+                {"kinds": ["statefulset"], "events": ["deployment_failed"], "to": ["slack:C1", "discord"], "continue": true},
+                {"selector": "team=payments", "to": ["slack:C1", "slack:C2"], "continue": true},
+                {"to": ["webhook"]},
+                {"to": ["teams"]}
+            ]"#,
+            &["slack"],
+        );
+
+        assert_eq!(
+            router.route(
+                &event("StatefulSet", "default"),
+                &labels(&[("team", "payments")])
+            ),
+            vec!["slack:C1", "discord", "slack:C2", "webhook"]
+        );
+        assert_eq!(
+            router.route(&event("Deployment", "default"), &labels(&[])),
+            vec!["webhook"]
+        );
+    }
+
+    #[test]
+    fn unmatched_event_uses_default_route() {
+        let router = router(
+            r#"[
+                {"namespaces": ["payments-*"], "to": ["slack:C1"]},
+                {"namespaces": ["kube-system"], "to": []}
+            ]"#,
+            &["slack", "teams"],
+        );
+
+        assert_eq!(
+            router.route(&event("Deployment", "search"), &labels(&[])),
+            vec!["slack", "teams"]
+        );
+        // 일치했지만 목적지가 비어 있으면 기본 route로 보내지 않음
+        assert!(
+            router
+                .route(&event("Deployment", "kube-system"), &labels(&[]))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn events_filter_uses_event_type() {
+        let router = router(
+            r#"[{"events": ["deployment_started"], "to": ["slack:C1"]}]"#,
+            &["slack"],
+        );
+
+        assert_eq!(
+            router.route(&event("Deployment", "default"), &labels(&[])),
+            vec!["slack"]
+        );
+    }
+}
@@ -0,0 +1,558 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Serialize;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use super::blocks::{SlackLink, render_blocks, with_progress};
+use super::card::{Card, Tone, build_card};
+use super::{Language, Notifier, NotifierConfig, format_message};
+use crate::detector::DeploymentEvent;
+use crate::retry::{SendError, retry_after};
+use crate::state::{RolloutProgress, SlackThread, StateManager};
+
+// chat.update는 Tier 3 (분당 약 50회)이므로 한 주기에 갱신하는 메시지 수를 제한
+const MAX_UPDATES_PER_TICK: usize = 5;
+
+/// 모든 채널의 진행 상황 갱신이 함께 쓰는 chat.update 횟수 제한
+///
+/// rate limit은 채널이 아닌 token 단위이므로 routing으로 채널이 늘어나도 주기당 MAX_UPDATES_PER_TICK회
+#[derive(Clone, Default)]
+pub struct UpdateLimiter {
+    window: Arc<std::sync::Mutex<(Option<Instant>, usize)>>, // (주기 시작 시각, 사용한 횟수)
+}
+
+impl UpdateLimiter {
+    fn try_acquire(&self, period: Duration) -> bool {
+        let mut window = self.window.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        if window
+            .0
+            .is_none_or(|start| now.duration_since(start) >= period)
+        {
+            *window = (Some(now), 0);
+        }
+        if window.1 >= MAX_UPDATES_PER_TICK {
+            return false;
+        }
+        window.1 += 1;
+        true
+    }
+}
+
+/// Slack으로 보내는 방법
+pub enum SlackTarget {
+    /// bot token으로 chat.postMessage 호출
+    Bot { token: String, channel: String },
+    /// incoming webhook (채널은 webhook에 고정되고 응답에 ts가 없어 thread와 메시지 수정 불가)
+    Webhook { url: String },
+}
+
+/// Block Kit 메시지 전송
+///
+/// bot token을 사용하면 배포 시작 메시지의 ts를 StateManager에 기록해두고
+/// 완료, 스케일, 실패 알림은 그 메시지의 thread 답글로 전송
+pub struct SlackNotifier {
+    name: String,
+    client: reqwest::Client,
+    target: SlackTarget,
+    language: Language,
+    threads: bool,
+    reply_broadcast: bool, // thread 답글을 채널에도 함께 표시
+    live_updates: bool,    // 시작 메시지를 진행 상황에 맞춰 수정하고 완료 시 완료 제목으로 변경
+    links: Vec<SlackLink>,
+    cluster_name: Option<String>,
+    state_manager: StateManager,
+    edit_lock: Arc<Mutex<()>>, // 완료 수정과 진행 상황 수정이 같은 메시지를 동시에 바꾸지 않도록
+}
+
+impl SlackNotifier {
+    pub fn new(
+        config: &NotifierConfig,
+        target: SlackTarget,
+        state_manager: StateManager,
+        limiter: UpdateLimiter,
+    ) -> Self {
+        let threads = config.slack_threads && matches!(target, SlackTarget::Bot { .. });
+        let live_updates = threads && config.slack_update_interval.is_some();
+        let edit_lock = Arc::new(Mutex::new(()));
+
+        // 진행 중에는 watch 이벤트가 알림을 만들지 않으므로 상태를 주기적으로 확인
+        if let SlackTarget::Bot { token, channel } = &target
+            && let Some(interval) = config.slack_update_interval.filter(|_| live_updates)
+        {
+            let updater = ProgressUpdater {
+                client: config.http_client.clone(),
+                token: token.clone(),
+                channel: channel.clone(),
+                language: config.language,
+                state_manager: state_manager.clone(),
+                edit_lock: edit_lock.clone(),
+                limiter,
+            };
+            tokio::spawn(updater.run(interval));
+        }
+
+        // routing으로 채널을 여러 개 쓸 수 있으므로 채널을 이름에 표시
+        let name = match &target {
+            SlackTarget::Bot { channel, .. } => format!("Slack ({})", channel),
+            SlackTarget::Webhook { .. } => "Slack".to_string(),
+        };
+
+        Self {
+            name,
+            client: config.http_client.clone(),
+            target,
+            language: config.language,
+            threads,
+            reply_broadcast: config.slack_reply_broadcast,
+            live_updates,
+            links: config.slack_links.clone(),
+            cluster_name: config.cluster_name.clone(),
+            state_manager,
+            edit_lock,
+        }
+    }
+
+    fn message(&self, event: &DeploymentEvent) -> SlackMessage {
+        let card = build_card(event, self.language);
+
+        SlackMessage {
+            channel: match &self.target {
+                SlackTarget::Bot { channel, .. } => Some(channel.clone()),
+                SlackTarget::Webhook { .. } => None,
+            },
+            // 알림과 스크린 리더에 표시되는 대체 텍스트
+            text: format_message(event, self.language),
+            // 이벤트별 색 막대를 표시하기 위해 blocks를 attachment 안에 넣음
+            attachments: vec![SlackAttachment {
+                color: slack_color(card.tone),
+                blocks: render_blocks(&card, event, &self.links, self.cluster_name.as_deref()),
+            }],
+            thread_ts: None,
+            reply_broadcast: None,
+        }
+    }
+
+    // 전송한 메시지의 ts 반환 (webhook은 None)
+    async fn post(&self, slack_message: &SlackMessage) -> Result<Option<String>, SendError> {
+        match &self.target {
+            SlackTarget::Bot { token, .. } => {
+                post_message(&self.client, token, slack_message).await
+            }
+            SlackTarget::Webhook { url } => {
+                post_webhook(&self.client, url, slack_message).await?;
+                Ok(None)
+            }
+        }
+    }
+
+    // 답글을 달 thread (같은 워크로드의 같은 generation에서 시작된 메시지)
+    async fn thread(&self, event: &DeploymentEvent) -> Option<SlackThread> {
+        let SlackTarget::Bot { channel, .. } = &self.target else {
+            return None;
+        };
+        let state = self.state_manager.get(&event.key()).await?;
+        let thread = state.slack_threads.get(channel)?.clone();
+
+        // 스케일처럼 generation이 없는 이벤트는 가장 최근 thread에 답글
+        match event.generation() {
+            Some(generation) if thread.generation != Some(generation) => None,
+            _ => Some(thread),
+        }
+    }
+
+    // 완료 이벤트면 시작 메시지를 완료 상태로 수정
+    // 수정할 메시지가 없거나 메시지가 삭제되는 등 수정할 수 없으면 false (답글로 대신 전송)
+    async fn complete_thread(
+        &self,
+        event: &DeploymentEvent,
+        thread: &SlackThread,
+    ) -> Result<bool, SendError> {
+        let replicas = match event {
+            DeploymentEvent::DeploymentCompleted { replicas, .. }
+            | DeploymentEvent::DeploymentRestartCompleted { replicas, .. }
+            | DeploymentEvent::ReplicaScaleCompleted { replicas, .. } => *replicas,
+            _ => return Ok(false),
+        };
+        let SlackTarget::Bot { token, channel } = &self.target else {
+            return Ok(false);
+        };
+        let card = build_card(event, self.language);
+        let progress = RolloutProgress {
+            updated: replicas,
+            ready: replicas,
+            desired: replicas,
+        };
+        let Some(attachment) = thread.message.as_ref().and_then(|m| {
+            updated_attachment(m, &progress_text(&progress, self.language), Some(&card))
+        }) else {
+            return Ok(false);
+        };
+
+        let text = format_message(event, self.language);
+        let _guard = self.edit_lock.lock().await;
+        let updated =
+            match update_message(&self.client, token, channel, &thread.ts, &text, attachment).await
+            {
+                Ok(()) => true,
+                Err(e @ SendError::Retryable { .. }) => return Err(e),
+                Err(e) => {
+                    log::warn!("Failed to update Slack message, replying instead: {}", e);
+                    false
+                }
+            };
+        // 수정하지 못한 경우에도 진행 상황 갱신은 멈춤
+        self.state_manager
+            .update_slack_thread(&event.key(), channel, &thread.ts, |t| {
+                if updated {
+                    t.progress = Some(progress);
+                }
+                t.completed = true;
+            })
+            .await;
+        Ok(updated)
+    }
+}
+
+// 새 thread를 시작하는 이벤트
+fn starts_thread(event: &DeploymentEvent) -> bool {
+    matches!(
+        event,
+        DeploymentEvent::DeploymentStarted { .. }
+            | DeploymentEvent::DeploymentRestarted { .. }
+            | DeploymentEvent::DeploymentRolledBack { .. }
+            | DeploymentEvent::ReplicaScaleStarted { .. }
+    )
+}
+
+#[async_trait]
+impl Notifier for SlackNotifier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, event: &DeploymentEvent) -> Result<(), SendError> {
+        let mut slack_message = self.message(event);
+
+        if !self.threads {
+            self.post(&slack_message).await?;
+            return Ok(());
+        }
+
+        if starts_thread(event) {
+            let ts = self.post(&slack_message).await?;
+            if let (Some(ts), SlackTarget::Bot { channel, .. }) = (ts, &self.target) {
+                let thread = SlackThread {
+                    generation: event.generation(),
+                    ts,
+                    message: slack_message
+                        .attachments
+                        .first()
+                        .and_then(|a| serde_json::to_value(a).ok()),
+                    ..Default::default()
+                };
+                self.state_manager
+                    .set_slack_thread(&event.key(), channel, thread)
+                    .await;
+            }
+            return Ok(());
+        }
+
+        if let Some(thread) = self.thread(event).await {
+            if self.live_updates
+                && !thread.completed
+                && self.complete_thread(event, &thread).await?
+            {
+                return Ok(());
+            }
+            slack_message.thread_ts = Some(thread.ts);
+            slack_message.reply_broadcast = self.reply_broadcast.then_some(true);
+        }
+        self.post(&slack_message).await?;
+        Ok(())
+    }
+}
+
+/// 진행 중인 배포의 시작 메시지를 chat.update로 갱신
+struct ProgressUpdater {
+    client: reqwest::Client,
+    token: String,
+    channel: String,
+    language: Language,
+    state_manager: StateManager,
+    edit_lock: Arc<Mutex<()>>,
+    limiter: UpdateLimiter,
+}
+
+impl ProgressUpdater {
+    async fn run(self, update_interval: Duration) {
+        let mut interval = tokio::time::interval(update_interval);
+        loop {
+            interval.tick().await;
+
+            for (key, thread, progress) in self.pending().await {
+                if !self.limiter.try_acquire(update_interval) {
+                    break;
+                }
+                // 목록을 만든 뒤 완료 알림이 메시지를 바꿨으면 덮어쓰지 않음
+                let _guard = self.edit_lock.lock().await;
+                if !self.is_open(&key, &thread.ts).await {
+                    continue;
+                }
+
+                let text = progress_text(&progress, self.language);
+                let Some(attachment) = thread
+                    .message
+                    .as_ref()
+                    .and_then(|m| updated_attachment(m, &text, None))
+                else {
+                    continue;
+                };
+
+                match update_message(
+                    &self.client,
+                    &self.token,
+                    &self.channel,
+                    &thread.ts,
+                    &text,
+                    attachment,
+                )
+                .await
+                {
+                    Ok(()) => {}
+                    // rate limit 등은 다음 주기에 다시 시도
+                    Err(e @ SendError::Retryable { .. }) => {
+                        log::warn!("Failed to update Slack message, retrying later: {}", e);
+                        break;
+                    }
+                    // 메시지가 삭제된 경우 등은 같은 진행 상황으로 다시 시도하지 않음
+                    Err(e) => log::warn!("Failed to update Slack message: {}", e),
+                }
+                self.state_manager
+                    .update_slack_thread(&key, &self.channel, &thread.ts, |t| {
+                        t.progress = Some(progress)
+                    })
+                    .await;
+            }
+        }
+    }
+
+    async fn is_open(&self, key: &str, ts: &str) -> bool {
+        self.state_manager
+            .get(key)
+            .await
+            .and_then(|s| s.slack_threads.get(&self.channel).cloned())
+            .is_some_and(|t| t.ts == ts && !t.completed)
+    }
+
+    // 진행 상황이 바뀐 thread 목록 (완료된 배포는 완료 이벤트에서 처리)
+    async fn pending(&self) -> Vec<(String, SlackThread, RolloutProgress)> {
+        let mut pending = Vec::new();
+        self.state_manager
+            .for_each(|key, state| {
+                let Some(thread) = state.slack_threads.get(&self.channel) else {
+                    return;
+                };
+                let progress = RolloutProgress {
+                    updated: state.updated_replicas,
+                    ready: state.ready_replicas,
+                    desired: state.replicas,
+                };
+                let is_current = thread.generation.is_none_or(|g| g == state.generation);
+
+                if thread.message.is_some()
+                    && !thread.completed
+                    && !state.rollout_complete
+                    && is_current
+                    && thread.progress != Some(progress)
+                    && pending.len() < MAX_UPDATES_PER_TICK
+                {
+                    pending.push((key.to_string(), thread.clone(), progress));
+                }
+            })
+            .await;
+        pending
+    }
+}
+
+fn progress_text(progress: &RolloutProgress, language: Language) -> String {
+    match language {
+        Language::Korean => format!(
+            "업데이트 {}/{}, 준비 {}/{}",
+            progress.updated, progress.desired, progress.ready, progress.desired
+        ),
+        Language::English => format!(
+            "updated {}/{}, ready {}/{}",
+            progress.updated, progress.desired, progress.ready, progress.desired
+        ),
+    }
+}
+
+// 저장해둔 시작 메시지에 진행 상황을 넣고, card가 주어지면 제목과 색도 변경
+fn updated_attachment(
+    message: &serde_json::Value,
+    text: &str,
+    card: Option<&Card>,
+) -> Option<serde_json::Value> {
+    let blocks = with_progress(message.get("blocks")?, text, card.map(|c| c.title))?;
+    let mut attachment = message.clone();
+    attachment["blocks"] = blocks;
+    if let Some(card) = card {
+        attachment["color"] = slack_color(card.tone).into();
+    }
+    Some(attachment)
+}
+
+fn slack_color(tone: Tone) -> &'static str {
+    match tone {
+        Tone::InProgress => "warning",
+        Tone::Success => "good",
+        Tone::Warning => "#E8912D",
+        Tone::Failure => "danger",
+        Tone::Info => "#439FE0",
+    }
+}
+
+#[derive(Serialize)]
+struct SlackMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel: Option<String>, // webhook은 채널을 지정하지 않음
+    text: String,
+    attachments: Vec<SlackAttachment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_broadcast: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct SlackAttachment {
+    color: &'static str,
+    blocks: Vec<serde_json::Value>,
+}
+
+// 전송한 메시지의 ts 반환
+async fn post_message(
+    client: &reqwest::Client,
+    token: &str,
+    slack_message: &SlackMessage,
+) -> Result<Option<String>, SendError> {
+    let response_json = call_slack(client, token, "chat.postMessage", slack_message).await?;
+
+    Ok(response_json
+        .get("ts")
+        .and_then(|ts| ts.as_str())
+        .map(|ts| ts.to_string()))
+}
+
+// incoming webhook은 JSON이 아닌 "ok" 또는 "invalid_payload" 같은 텍스트로 응답
+async fn post_webhook(
+    client: &reqwest::Client,
+    url: &str,
+    slack_message: &SlackMessage,
+) -> Result<(), SendError> {
+    let response = client.post(url).json(slack_message).send().await?;
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    let response_text = response.text().await?;
+    if status.is_success() {
+        return Ok(());
+    }
+
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(SendError::Retryable {
+            message: format!("Slack webhook rate limited: {}", response_text),
+            retry_after: retry_after(&headers),
+        });
+    }
+    if status.is_server_error() {
+        return Err(SendError::retryable(format!(
+            "Slack webhook returned {}: {}",
+            status, response_text
+        )));
+    }
+    // channel_not_found, no_service(webhook 비활성화) 등
+    Err(slack_error(response_text.trim(), &headers))
+}
+
+async fn update_message(
+    client: &reqwest::Client,
+    token: &str,
+    channel: &str,
+    ts: &str,
+    text: &str,
+    attachment: serde_json::Value,
+) -> Result<(), SendError> {
+    let body = serde_json::json!({
+        "channel": channel,
+        "ts": ts,
+        "text": text,
+        "attachments": [attachment],
+    });
+    call_slack(client, token, "chat.update", &body).await?;
+    Ok(())
+}
+
+async fn call_slack<T: Serialize>(
+    client: &reqwest::Client,
+    token: &str,
+    method: &str,
+    body: &T,
+) -> Result<serde_json::Value, SendError> {
+    let response = client
+        .post(format!("https://slack.com/api/{}", method))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("Content-Type", "application/json")
+        .json(body)
+        .send()
+        .await?;
+
+    let status = response.status();
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(SendError::Retryable {
+            message: "Slack API rate limited".to_string(),
+            retry_after: retry_after(response.headers()),
+        });
+    }
+    if status.is_server_error() {
+        return Err(SendError::retryable(format!(
+            "Slack API returned {}",
+            status
+        )));
+    }
+
+    // Slack API 응답 확인
+    let headers = response.headers().clone();
+    let response_text = response.text().await?;
+
+    // Slack API는 200을 반환하지만 error 필드로 에러를 표시할 수 있음
+    let response_json: serde_json::Value = serde_json::from_str(&response_text)
+        .map_err(|e| SendError::Permanent(format!("Invalid Slack API response: {}", e)))?;
+    if let Some(ok) = response_json.get("ok")
+        && ok == &serde_json::Value::Bool(false)
+        && let Some(error) = response_json.get("error").and_then(|e| e.as_str())
+    {
+        return Err(slack_error(error, &headers));
+    }
+
+    Ok(response_json)
+}
+
+// 일시적인 Slack 오류만 재시도 (channel_not_found, invalid_auth 등은 설정을 고쳐야 함)
+fn slack_error(error: &str, headers: &reqwest::header::HeaderMap) -> SendError {
+    let message = format!("Slack API error: {}", error);
+    match error {
+        "ratelimited" => SendError::Retryable {
+            message,
+            retry_after: retry_after(headers),
+        },
+        "internal_error" | "fatal_error" | "service_unavailable" | "request_timeout" => {
+            SendError::retryable(message)
+        }
+        _ => SendError::Permanent(message),
+    }
+}
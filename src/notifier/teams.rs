@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use serde_json::json;
+
+use super::card::{Card, Tone, build_card};
+use super::{Language, Notifier};
+use crate::detector::DeploymentEvent;
+use crate::retry::{SendError, retry_after};
+
+/// Teams incoming webhook 또는 Workflows URL로 Adaptive Card 전송
+pub struct TeamsNotifier {
+    client: reqwest::Client,
+    webhook_url: String,
+    language: Language,
+}
+
+impl TeamsNotifier {
+    pub fn new(client: reqwest::Client, webhook_url: &str, language: Language) -> Self {
+        Self {
+            client,
+            webhook_url: webhook_url.to_string(),
+            language,
+        }
+    }
+}
+
+// Adaptive Card Container style
+fn container_style(tone: Tone) -> &'static str {
+    match tone {
+        Tone::InProgress => "warning",
+        Tone::Success => "good",
+        Tone::Warning => "warning",
+        Tone::Failure => "attention",
+        Tone::Info => "accent",
+    }
+}
+
+fn adaptive_card(card: &Card) -> serde_json::Value {
+    let mut body = vec![json!({
+        "type": "Container",
+        "style": container_style(card.tone),
+        "bleed": true,
+        "items": [{
+            "type": "TextBlock",
+            "text": card.title,
+            "weight": "Bolder",
+            "size": "Medium",
+            "wrap": true,
+        }],
+    })];
+
+    // 짧은 항목은 FactSet으로 모아서 표시하고, 이미지/변경 목록처럼 긴 항목은 따로 표시
+    let facts: Vec<serde_json::Value> = card
+        .fields
+        .iter()
+        .filter(|f| f.short)
+        .map(|f| json!({ "title": f.title, "value": f.value }))
+        .collect();
+    body.push(json!({ "type": "FactSet", "facts": facts }));
+
+    for field in card.fields.iter().filter(|f| !f.short) {
+        body.push(json!({
+            "type": "TextBlock",
+            "text": field.title,
+            "weight": "Bolder",
+            "spacing": "Medium",
+        }));
+        // TextBlock은 줄바꿈을 하나만 넣으면 무시하므로 빈 줄로 구분
+        body.push(json!({
+            "type": "TextBlock",
+            "text": field.value.replace('\n', "\n\n"),
+            "wrap": true,
+            "spacing": "None",
+        }));
+    }
+
+    json!({
+        "type": "message",
+        "attachments": [{
+            "contentType": "application/vnd.microsoft.card.adaptive",
+            "content": {
+                "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                "type": "AdaptiveCard",
+                "version": "1.4",
+                "msteams": { "width": "Full" },
+                "body": body,
+            },
+        }],
+    })
+}
+
+#[async_trait]
+impl Notifier for TeamsNotifier {
+    fn name(&self) -> &str {
+        "Teams"
+    }
+
+    async fn send(&self, event: &DeploymentEvent) -> Result<(), SendError> {
+        let card = build_card(event, self.language);
+
+        let response = self
+            .client
+            .post(&self.webhook_url)
+            .json(&adaptive_card(&card))
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(SendError::Retryable {
+                message: "Teams webhook rate limited".to_string(),
+                retry_after: retry_after(response.headers()),
+            });
+        }
+        if status.is_server_error() {
+            return Err(SendError::retryable(format!(
+                "Teams webhook returned {}",
+                status
+            )));
+        }
+
+        let response_text = response.text().await?;
+        if !status.is_success() {
+            return Err(SendError::Permanent(format!(
+                "Teams webhook returned {}: {}",
+                status, response_text
+            )));
+        }
+
+        // 기존 incoming webhook은 Teams 쪽 전송 실패도 200과 오류 문구로 응답
+        if response_text.contains("failed") {
+            let message = format!("Teams webhook error: {}", response_text);
+            return Err(if response_text.contains("429") {
+                SendError::retryable(message)
+            } else {
+                SendError::Permanent(message)
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,264 @@
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use k8s_openapi::jiff::Timestamp;
+use serde::Serialize;
+use sha2::Sha256;
+
+use super::{Language, Notifier, format_message};
+use crate::detector::DeploymentEvent;
+use crate::diff::ImageChange;
+use crate::retry::{SendError, retry_after};
+
+/// payload 형식이 호환되지 않게 바뀌면 올림
+const PAYLOAD_VERSION: u32 = 1;
+
+// HMAC-SHA256(secret, body)를 hex로 "sha256=<hex>" 형태로 전송
+const SIGNATURE_HEADER: &str = "X-Bellboy-Signature";
+
+/// 설정된 URL로 이벤트를 JSON으로 POST
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    name: String,
+    url: String,
+    headers: Vec<(String, String)>,
+    secret: Option<String>,
+    language: Language,
+}
+
+impl WebhookNotifier {
+    pub fn new(
+        client: reqwest::Client,
+        url: &str,
+        headers: Vec<(String, String)>,
+        secret: Option<String>,
+        language: Language,
+    ) -> Self {
+        // 로그에 query string(토큰 등)이 남지 않도록 host까지만 사용
+        let host = reqwest::Url::parse(url)
+            .ok()
+            .and_then(|u| u.host_str().map(|h| h.to_string()))
+            .unwrap_or_default();
+
+        Self {
+            client,
+            name: format!("Webhook ({})", host),
+            url: url.to_string(),
+            headers,
+            secret,
+            language,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+    version: u32,
+    #[serde(rename = "type")]
+    event_type: &'a str,
+    kind: &'a str,
+    namespace: &'a str,
+    name: &'a str,
+    generation: Option<i64>,
+    previous_generation: Option<i64>,
+    replicas: Option<i32>,
+    previous_replicas: Option<i32>,
+    images: Vec<WebhookImage<'a>>,
+    changes: Vec<String>, // 이미지 외 template 변경 요약
+    message: String,      // LANGUAGE에 맞춘 알림 문구
+    timestamp: String,    // RFC 3339
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<&'a str>, // DeploymentFailed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    elapsed_seconds: Option<u64>, // DeploymentStalled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rolled_back_to: Option<i64>, // DeploymentRolledBack
+}
+
+#[derive(Serialize)]
+struct WebhookImage<'a> {
+    container: &'a str,
+    init: bool,
+    previous: Option<&'a str>,
+    current: Option<&'a str>,
+}
+
+impl<'a> WebhookPayload<'a> {
+    fn new(event: &'a DeploymentEvent, language: Language) -> Self {
+        let (kind, namespace, name) = event.target();
+        let mut payload = WebhookPayload {
+            version: PAYLOAD_VERSION,
+            event_type: event.event_type(),
+            kind,
+            namespace,
+            name,
+            generation: None,
+            previous_generation: None,
+            replicas: None,
+            previous_replicas: None,
+            images: Vec::new(),
+            changes: Vec::new(),
+            message: format_message(event, language),
+            timestamp: Timestamp::now().to_string(),
+            reason: None,
+            elapsed_seconds: None,
+            rolled_back_to: None,
+        };
+
+        match event {
+            DeploymentEvent::DeploymentStarted {
+                old_generation,
+                new_generation,
+                old_replicas,
+                new_replicas,
+                image_changes,
+                template_changes,
+                ..
+            } => {
+                payload.generation = Some(*new_generation);
+                payload.previous_generation = Some(*old_generation);
+                payload.replicas = *new_replicas;
+                payload.previous_replicas = *old_replicas;
+                payload.images = webhook_images(image_changes);
+                payload.changes = template_changes.summary();
+            }
+            DeploymentEvent::DeploymentRestarted {
+                old_generation,
+                new_generation,
+                ..
+            } => {
+                payload.generation = Some(*new_generation);
+                payload.previous_generation = Some(*old_generation);
+            }
+            DeploymentEvent::DeploymentRestartCompleted {
+                generation,
+                replicas,
+                ..
+            } => {
+                payload.generation = Some(*generation);
+                payload.replicas = Some(*replicas);
+            }
+            DeploymentEvent::DeploymentRolledBack {
+                old_generation,
+                new_generation,
+                rolled_back_to,
+                ..
+            } => {
+                payload.generation = Some(*new_generation);
+                payload.previous_generation = Some(*old_generation);
+                payload.rolled_back_to = Some(*rolled_back_to);
+            }
+            DeploymentEvent::DeploymentCompleted {
+                generation,
+                replicas,
+                replica_changed,
+                image_changes,
+                template_changes,
+                ..
+            } => {
+                payload.generation = Some(*generation);
+                payload.replicas = Some(*replicas);
+                payload.previous_replicas = replica_changed.map(|(old, _)| old);
+                payload.images = webhook_images(image_changes);
+                payload.changes = template_changes.summary();
+            }
+            DeploymentEvent::ReplicaScaleStarted {
+                old_replicas,
+                new_replicas,
+                ..
+            } => {
+                payload.replicas = Some(*new_replicas);
+                payload.previous_replicas = Some(*old_replicas);
+            }
+            DeploymentEvent::ReplicaScaleCompleted { replicas, .. } => {
+                payload.replicas = Some(*replicas);
+            }
+            DeploymentEvent::DeploymentStalled {
+                generation,
+                elapsed_seconds,
+                replicas,
+                ..
+            } => {
+                payload.generation = Some(*generation);
+                payload.replicas = Some(*replicas);
+                payload.elapsed_seconds = Some(*elapsed_seconds);
+            }
+            DeploymentEvent::DeploymentFailed {
+                generation, reason, ..
+            } => {
+                payload.generation = Some(*generation);
+                payload.reason = Some(reason);
+            }
+            DeploymentEvent::RolloutProgressed {
+                generation,
+                desired,
+                ..
+            } => {
+                payload.generation = Some(*generation);
+                payload.replicas = Some(*desired);
+            }
+        }
+
+        payload
+    }
+}
+
+fn webhook_images(image_changes: &[ImageChange]) -> Vec<WebhookImage<'_>> {
+    image_changes
+        .iter()
+        .map(|c| WebhookImage {
+            container: &c.container,
+            init: c.init,
+            previous: c.old_image.as_deref(),
+            current: c.new_image.as_deref(),
+        })
+        .collect()
+}
+
+fn sign(secret: &str, body: &[u8]) -> String {
+    // HMAC은 모든 길이의 key를 받으므로 실패하지 않음
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, event: &DeploymentEvent) -> Result<(), SendError> {
+        let payload = WebhookPayload::new(event, self.language);
+        let body = serde_json::to_vec(&payload).map_err(|e| SendError::Permanent(e.to_string()))?;
+
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header("Content-Type", "application/json");
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        if let Some(secret) = &self.secret {
+            request = request.header(SIGNATURE_HEADER, sign(secret, &body));
+        }
+
+        let response = request.body(body).send().await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let message = format!("Webhook returned {}", status);
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Err(SendError::Retryable {
+                message,
+                retry_after: retry_after(response.headers()),
+            })
+        } else if status.is_server_error() || status == reqwest::StatusCode::REQUEST_TIMEOUT {
+            Err(SendError::retryable(message))
+        } else {
+            Err(SendError::Permanent(message))
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use k8s_openapi::api::core::v1::ConfigMap;
+use kube::api::{ObjectMeta, Patch, PatchParams};
+use kube::{Api, Client};
+
+use crate::state::DeploymentState;
+
+pub type StoreError = Box<dyn std::error::Error + Send + Sync>;
+
+// ConfigMap 안에서 상태를 저장하는 key
+const CONFIGMAP_DATA_KEY: &str = "states.json";
+
+// ConfigMap 크기 제한 (1MiB)과 경고를 시작할 크기
+const CONFIGMAP_SIZE_LIMIT: usize = 1024 * 1024;
+const CONFIGMAP_SIZE_WARNING: usize = CONFIGMAP_SIZE_LIMIT * 8 / 10;
+
+/// bellboy 재시작 후에도 감지 상태를 이어가기 위한 저장소
+#[async_trait]
+pub trait StateStore: Send + Sync {
+    async fn load(&self) -> Result<HashMap<String, DeploymentState>, StoreError>;
+    async fn save(&self, states: &HashMap<String, DeploymentState>) -> Result<(), StoreError>;
+}
+
+/// 로컬 개발용 파일 저장소
+pub struct FileStore {
+    path: PathBuf,
+}
+
+impl FileStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl StateStore for FileStore {
+    async fn load(&self) -> Result<HashMap<String, DeploymentState>, StoreError> {
+        match tokio::fs::read_to_string(&self.path).await {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            // 처음 실행하는 경우
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn save(&self, states: &HashMap<String, DeploymentState>) -> Result<(), StoreError> {
+        let content = serde_json::to_string(states)?;
+
+        // 쓰는 도중 종료되어도 기존 파일이 깨지지 않도록 임시 파일에 쓰고 교체
+        let tmp_path = self.path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, content).await?;
+        tokio::fs::rename(&tmp_path, &self.path).await?;
+
+        Ok(())
+    }
+}
+
+/// 클러스터 안에서 사용하는 ConfigMap 저장소
+pub struct ConfigMapStore {
+    api: Api<ConfigMap>,
+    name: String,
+}
+
+impl ConfigMapStore {
+    pub fn new(client: Client, namespace: &str, name: &str) -> Self {
+        Self {
+            api: Api::namespaced(client, namespace),
+            name: name.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl StateStore for ConfigMapStore {
+    async fn load(&self) -> Result<HashMap<String, DeploymentState>, StoreError> {
+        let Some(config_map) = self.api.get_opt(&self.name).await? else {
+            return Ok(HashMap::new());
+        };
+
+        match config_map
+            .data
+            .and_then(|d| d.get(CONFIGMAP_DATA_KEY).cloned())
+        {
+            Some(content) => Ok(serde_json::from_str(&content)?),
+            None => Ok(HashMap::new()),
+        }
+    }
+
+    async fn save(&self, states: &HashMap<String, DeploymentState>) -> Result<(), StoreError> {
+        let content = serde_json::to_string(states)?;
+
+        // 제한을 넘으면 API server가 거부하므로 요청 전에 원인을 알 수 있는 오류로 반환
+        if content.len() >= CONFIGMAP_SIZE_LIMIT {
+            return Err(format!(
+                "state of {} workloads is {} bytes, over the ConfigMap limit of {} bytes; \
+                 narrow the watched namespaces or disable persistence",
+                states.len(),
+                content.len(),
+                CONFIGMAP_SIZE_LIMIT
+            )
+            .into());
+        }
+        if content.len() >= CONFIGMAP_SIZE_WARNING {
+            log::warn!(
+                "State of {} workloads is {} bytes, close to the ConfigMap limit of {} bytes",
+                states.len(),
+                content.len(),
+                CONFIGMAP_SIZE_LIMIT
+            );
+        }
+
+        let config_map = ConfigMap {
+            metadata: ObjectMeta {
+                name: Some(self.name.clone()),
+                ..Default::default()
+            },
+            data: Some(BTreeMap::from([(CONFIGMAP_DATA_KEY.to_string(), content)])),
+            ..Default::default()
+        };
+
+        // 없으면 생성, 있으면 덮어쓰기
+        let params = PatchParams::apply("bellboy").force();
+        self.api
+            .patch(&self.name, &params, &Patch::Apply(&config_map))
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// STATE_BACKEND 환경 변수로 저장소 선택 (configmap 또는 file, 미설정 시 메모리에만 보관)
+pub fn store_from_env(client: Client) -> Option<Box<dyn StateStore>> {
+    let backend = std::env::var("STATE_BACKEND").ok()?;
+
+    match backend.to_lowercase().as_str() {
+        "configmap" => {
+            let namespace = std::env::var("STATE_CONFIGMAP_NAMESPACE")
+                .or_else(|_| std::env::var("POD_NAMESPACE"))
+                .unwrap_or("default".to_string());
+            let name = std::env::var("STATE_CONFIGMAP_NAME").unwrap_or("bellboy-state".to_string());
+            log::info!("State backend: ConfigMap {}/{}", namespace, name);
+            Some(Box::new(ConfigMapStore::new(client, &namespace, &name)))
+        }
+        "file" => {
+            let path = std::env::var("STATE_FILE").unwrap_or("bellboy-state.json".to_string());
+            log::info!("State backend: file {}", path);
+            Some(Box::new(FileStore::new(path)))
+        }
+        _ => {
+            log::warn!(
+                "Unknown STATE_BACKEND: {}, state will not be persisted",
+                backend
+            );
+            None
+        }
+    }
+}
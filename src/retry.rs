@@ -0,0 +1,158 @@
+use std::fmt;
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// 알림 전송 실패 시 재시도 정책
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub max_attempts: u32, // 첫 시도 포함
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// NOTIFY_RETRY_ATTEMPTS, NOTIFY_RETRY_MAX_BACKOFF_SECONDS, NOTIFY_RETRY_JITTER 환경 변수로 설정
+    pub fn from_env() -> Self {
+        let default = Self::default();
+
+        let max_attempts = std::env::var("NOTIFY_RETRY_ATTEMPTS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(default.max_attempts)
+            .max(1);
+        let max_backoff = std::env::var("NOTIFY_RETRY_MAX_BACKOFF_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(default.max_backoff);
+        let jitter = std::env::var("NOTIFY_RETRY_JITTER")
+            .map(|v| v != "false")
+            .unwrap_or(default.jitter);
+
+        Self {
+            max_attempts,
+            max_backoff,
+            jitter,
+            ..default
+        }
+    }
+
+    // attempt번째 실패 후 대기 시간 (지수 증가, max_backoff로 제한)
+    fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff);
+
+        if !self.jitter {
+            return backoff;
+        }
+
+        // 여러 worker가 동시에 재시도하지 않도록 50~100% 사이에서 무작위로 선택
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or(0);
+        backoff.mul_f64(0.5 + (nanos % 1000) as f64 / 2000.0)
+    }
+}
+
+#[derive(Debug)]
+pub enum SendError {
+    /// 일시적인 오류 (네트워크 오류, 5xx, rate limit)
+    Retryable {
+        message: String,
+        retry_after: Option<Duration>, // 서버가 알려준 대기 시간 (Retry-After)
+    },
+    /// 재시도해도 성공할 수 없는 오류 (인증 실패, 채널 없음 등)
+    Permanent(String),
+}
+
+impl SendError {
+    pub fn retryable(message: impl Into<String>) -> Self {
+        SendError::Retryable {
+            message: message.into(),
+            retry_after: None,
+        }
+    }
+}
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SendError::Retryable { message, .. } => write!(f, "{}", message),
+            SendError::Permanent(message) => write!(f, "{} (not retried)", message),
+        }
+    }
+}
+
+impl std::error::Error for SendError {}
+
+impl From<reqwest::Error> for SendError {
+    fn from(e: reqwest::Error) -> Self {
+        SendError::retryable(e.to_string())
+    }
+}
+
+/// Retry-After 헤더 (초 단위만 지원)
+pub fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Retryable 오류면 정책에 따라 재시도하고, Permanent 오류나 마지막 시도의 오류는 그대로 반환
+pub async fn with_retry<T, F, Fut>(
+    policy: &RetryPolicy,
+    target: &str,
+    mut send: F,
+) -> Result<T, SendError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, SendError>>,
+{
+    let mut attempt = 1;
+    loop {
+        match send().await {
+            Ok(value) => return Ok(value),
+            Err(SendError::Retryable {
+                message,
+                retry_after,
+            }) if attempt < policy.max_attempts => {
+                let wait = retry_after.unwrap_or_else(|| policy.backoff(attempt));
+                log::warn!(
+                    "{} notification failed (attempt {}/{}), retrying in {:.1}s: {}",
+                    target,
+                    attempt,
+                    policy.max_attempts,
+                    wait.as_secs_f64(),
+                    message
+                );
+                tokio::time::sleep(wait).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
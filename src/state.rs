@@ -1,33 +1,118 @@
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::Mutex;
 
-#[derive(Clone, Debug)]
+use crate::diff::{ImageChange, TemplateChanges};
+use crate::persistence::StateStore;
+
+// 저장된 상태에 없는 필드는 기본값으로 복원 (버전 업그레이드 대비)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DeploymentState {
-    #[allow(unused)]
+    pub kind: String, // Deployment, StatefulSet 등 워크로드 종류
     pub namespace: String,
-    #[allow(unused)]
     pub name: String,
+    pub labels: BTreeMap<String, String>, // 알림 routing의 label selector에서 사용
     pub generation: i64,
     pub replicas: i32,
     pub ready_replicas: i32,
     pub available_replicas: i32,
     pub updated_replicas: i32,
     pub observed_generation: i64,
-    pub pod_template_hash: String,      // Pod template 전체 해시 (구분용)
+    // Pod template JSON (env 값이 들어 있으므로 저장하지 않고 변경 내역 비교에만 사용)
+    #[serde(skip)]
+    pub pod_template: String,
+    pub template_digest: u64,           // Pod template 해시 (변경 구분용)
+    pub rollout_complete: bool,         // 워크로드 종류별 기준으로 판단한 롤아웃 완료 여부
+    pub partition: i32,                 // StatefulSet 단계적 배포 partition
+    pub stall_timeout_seconds: Option<u64>, // annotation으로 지정한 멈춤 판단 시간
     pub last_completed_generation: i64, // 마지막으로 완료 이벤트를 발생시킨 generation
     pub last_scaled_replicas: i32,      // 마지막으로 스케일 완료 이벤트를 발생시킨 replicas
+    pub last_progress_milestone: i32,   // 마지막으로 알린 진행률 구간 (25% 단위, DaemonSet)
+    pub last_failed_generation: i64,    // 마지막으로 실패 이벤트를 발생시킨 generation
+    pub rollout_started_at: Option<u64>, // 배포 시작 이벤트를 발생시킨 시각 (unix seconds)
+    pub last_stalled_generation: i64,   // 마지막으로 멈춤 이벤트를 발생시킨 generation
+    pub template_history: Vec<(i64, u64)>, // 과거 Pod template (배포된 generation, template 해시)
+    pub pending_image_changes: Vec<ImageChange>, // 진행 중인 배포의 이미지 변경 내역
+    pub pending_template_changes: TemplateChanges, // 진행 중인 배포의 이미지 외 변경 요약
+    pub pending_restart: bool,          // 진행 중인 배포가 kubectl rollout restart인지 여부
+    pub slack_threads: BTreeMap<String, SlackThread>, // 채널별 마지막 Slack thread (알림 worker가 기록)
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SlackThread {
+    pub generation: Option<i64>, // thread를 시작한 이벤트의 generation (스케일은 None)
+    pub ts: String,
+    // chat.update로 다시 보낼 시작 메시지 (크기가 커서 저장하지 않음, 재시작 후에는 답글로 전송)
+    #[serde(skip)]
+    pub message: Option<serde_json::Value>,
+    pub progress: Option<RolloutProgress>, // 시작 메시지에 마지막으로 반영한 진행 상황
+    pub completed: bool,                   // 완료로 갱신되어 더 이상 수정하지 않음
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RolloutProgress {
+    pub updated: i32,
+    pub ready: i32,
+    pub desired: i32,
 }
 
 #[derive(Clone)]
 pub struct StateManager {
     states: Arc<Mutex<HashMap<String, DeploymentState>>>,
+    store: Option<Arc<dyn StateStore>>,
+    dirty: Arc<AtomicBool>, // 마지막 저장 이후 변경 여부
 }
 
 impl StateManager {
     pub fn new() -> Self {
         Self {
             states: Arc::new(Mutex::new(HashMap::new())),
+            store: None,
+            dirty: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn with_store(store: Box<dyn StateStore>) -> Self {
+        Self {
+            store: Some(Arc::from(store)),
+            ..Self::new()
+        }
+    }
+
+    /// 저장소에서 이전 상태를 불러옴 (시작 시 한 번 호출)
+    pub async fn restore(&self) {
+        let Some(store) = &self.store else {
+            return;
+        };
+
+        match store.load().await {
+            Ok(loaded) => {
+                log::info!("Restored state of {} workloads", loaded.len());
+                let mut states = self.states.lock().await;
+                states.extend(loaded);
+            }
+            Err(e) => log::error!("Failed to restore state: {}", e),
+        }
+    }
+
+    /// 변경된 내용이 있으면 저장소에 기록
+    pub async fn persist(&self) {
+        let Some(store) = &self.store else {
+            return;
+        };
+        if !self.dirty.swap(false, Ordering::SeqCst) {
+            return;
+        }
+
+        let snapshot = self.states.lock().await.clone();
+        if let Err(e) = store.save(&snapshot).await {
+            log::error!("Failed to persist state: {}", e);
+            // 다음 주기에 다시 시도
+            self.dirty.store(true, Ordering::SeqCst);
         }
     }
 
@@ -36,14 +121,89 @@ impl StateManager {
         states.get(key).cloned()
     }
 
-    pub async fn update(&self, key: String, state: DeploymentState) {
+    pub async fn update(&self, key: String, mut state: DeploymentState) {
         let mut states = self.states.lock().await;
+        // 알림 worker가 기록한 값은 detector가 가진 이전 사본으로 덮어쓰지 않음
+        if let Some(existing) = states.get(&key) {
+            state.slack_threads = existing.slack_threads.clone();
+            // 주기적인 resync처럼 바뀐 것이 없는 이벤트는 저장하지 않음
+            if *existing == state {
+                return;
+            }
+        }
         states.insert(key, state);
+        self.dirty.store(true, Ordering::SeqCst);
+    }
+
+    pub async fn set_slack_thread(&self, key: &str, channel: &str, thread: SlackThread) {
+        let mut states = self.states.lock().await;
+        if let Some(state) = states.get_mut(key) {
+            state.slack_threads.insert(channel.to_string(), thread);
+            self.dirty.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// ts가 같은 thread일 때만 수정 (그 사이 새 배포로 thread가 바뀌었으면 무시)
+    pub async fn update_slack_thread<F>(&self, key: &str, channel: &str, ts: &str, f: F)
+    where
+        F: FnOnce(&mut SlackThread),
+    {
+        let mut states = self.states.lock().await;
+        if let Some(thread) = states
+            .get_mut(key)
+            .and_then(|s| s.slack_threads.get_mut(channel))
+            .filter(|t| t.ts == ts)
+        {
+            f(thread);
+            self.dirty.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub async fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(&str, &DeploymentState),
+    {
+        let states = self.states.lock().await;
+        for (key, state) in states.iter() {
+            f(key, state);
+        }
+    }
+
+    /// f가 상태를 바꿨으면 true를 반환 (바뀐 것이 없으면 저장하지 않음)
+    pub async fn for_each_mut<F>(&self, mut f: F)
+    where
+        F: FnMut(&str, &mut DeploymentState) -> bool,
+    {
+        let mut states = self.states.lock().await;
+        let mut changed = false;
+        for (key, state) in states.iter_mut() {
+            changed |= f(key, state);
+        }
+        if changed {
+            self.dirty.store(true, Ordering::SeqCst);
+        }
     }
 
-    #[allow(dead_code)]
     pub async fn remove(&self, key: &str) {
         let mut states = self.states.lock().await;
-        states.remove(key);
+        if states.remove(key).is_some() {
+            self.dirty.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// kind의 상태 중 keys에 없는 것을 삭제 (bellboy가 꺼져 있는 동안 삭제된 워크로드)
+    pub async fn retain_kind(&self, kind: &str, keys: &HashSet<String>) {
+        let prefix = format!("{}/", kind);
+        let mut states = self.states.lock().await;
+        let before = states.len();
+        states.retain(|key, _| !key.starts_with(&prefix) || keys.contains(key));
+        if states.len() != before {
+            log::info!(
+                "Removed state of {} deleted {} workloads",
+                before - states.len(),
+                kind
+            );
+            self.dirty.store(true, Ordering::SeqCst);
+        }
     }
 }
@@ -0,0 +1,301 @@
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::core::v1::PodTemplateSpec;
+use kube::Resource;
+
+/// 롤아웃 감지에 필요한 값을 제공하는 워크로드 공통 인터페이스
+///
+/// 새로운 종류(커스텀 리소스 포함)를 지원하려면 이 trait을 구현하고
+/// main.rs에서 watcher를 하나 추가하면 됨
+pub trait Workload: Resource<DynamicType = ()> {
+    fn kind_name(&self) -> String {
+        Self::kind(&()).to_string()
+    }
+
+    fn namespace_name(&self) -> String {
+        self.meta()
+            .namespace
+            .clone()
+            .unwrap_or("default".to_string())
+    }
+
+    fn workload_name(&self) -> String {
+        self.meta().name.clone().unwrap_or("unknown".to_string())
+    }
+
+    fn generation(&self) -> i64 {
+        self.meta().generation.unwrap_or(0)
+    }
+
+    /// StateManager 키 ("kind/namespace/name")
+    ///
+    /// 종류가 다르면 같은 이름이 있을 수 있으므로 kind까지 키에 포함
+    fn state_key(&self) -> String {
+        format!(
+            "{}/{}/{}",
+            self.kind_name(),
+            self.namespace_name(),
+            self.workload_name()
+        )
+    }
+
+    fn observed_generation(&self) -> i64;
+    fn desired_replicas(&self) -> i32;
+    fn ready_replicas(&self) -> i32;
+    fn available_replicas(&self) -> i32;
+    fn updated_replicas(&self) -> i32;
+
+    fn pod_template(&self) -> Option<&PodTemplateSpec>;
+
+    /// Pod template 변경 여부를 구분하기 위한 값
+    fn template_fingerprint(&self) -> String {
+        self.pod_template()
+            .and_then(|t| serde_json::to_string(t).ok())
+            .unwrap_or_default()
+    }
+
+    fn is_rollout_complete(&self) -> bool {
+        let replicas = self.desired_replicas();
+        self.observed_generation() == self.generation()
+            && self.ready_replicas() == replicas
+            && self.available_replicas() == replicas
+            && self.updated_replicas() == replicas
+    }
+
+    /// bellboy가 시작될 때 이미 진행 중이던 배포인지 여부
+    ///
+    /// 준비되지 않은 Pod가 있는 것만으로는 배포 중으로 보지 않음 (crash loop 등)
+    fn is_rollout_in_progress(&self) -> bool {
+        self.observed_generation() < self.generation()
+            || self.updated_replicas() < self.desired_replicas()
+    }
+
+    /// 롤아웃이 실패한 경우 (reason, message) 반환
+    ///
+    /// 현재 generation을 controller가 반영한 뒤의 status만 보고 판단해야 함
+    fn rollout_failure(&self) -> Option<(String, String)> {
+        None
+    }
+
+    /// 이 ordinal 미만은 갱신하지 않는 단계적 배포 기준 (StatefulSet partition)
+    fn partition(&self) -> i32 {
+        0
+    }
+
+    /// false면 replica 수 변화를 스케일 이벤트로 알리지 않고 대신 진행률을 알림
+    fn tracks_replicas(&self) -> bool {
+        true
+    }
+}
+
+impl Workload for Deployment {
+    fn observed_generation(&self) -> i64 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.observed_generation)
+            .unwrap_or(0)
+    }
+
+    fn desired_replicas(&self) -> i32 {
+        self.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1)
+    }
+
+    fn ready_replicas(&self) -> i32 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.ready_replicas)
+            .unwrap_or(0)
+    }
+
+    fn available_replicas(&self) -> i32 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.available_replicas)
+            .unwrap_or(0)
+    }
+
+    fn updated_replicas(&self) -> i32 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.updated_replicas)
+            .unwrap_or(0)
+    }
+
+    fn pod_template(&self) -> Option<&PodTemplateSpec> {
+        self.spec.as_ref().map(|s| &s.template)
+    }
+
+    // progressDeadlineSeconds를 넘기면 Progressing 조건이 False / ProgressDeadlineExceeded로 바뀜
+    // controller가 새 generation을 반영하기 전에는 이전 generation의 조건이 남아 있으므로 무시
+    fn rollout_failure(&self) -> Option<(String, String)> {
+        if self.observed_generation() != self.generation() {
+            return None;
+        }
+        self.status
+            .as_ref()
+            .and_then(|s| s.conditions.as_ref())
+            .and_then(|conditions| {
+                conditions.iter().find(|c| {
+                    c.type_ == "Progressing"
+                        && c.status == "False"
+                        && c.reason.as_deref() == Some("ProgressDeadlineExceeded")
+                })
+            })
+            .map(|c| {
+                (
+                    "ProgressDeadlineExceeded".to_string(),
+                    c.message.clone().unwrap_or_default(),
+                )
+            })
+    }
+}
+
+impl Workload for StatefulSet {
+    fn observed_generation(&self) -> i64 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.observed_generation)
+            .unwrap_or(0)
+    }
+
+    fn desired_replicas(&self) -> i32 {
+        self.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1)
+    }
+
+    fn ready_replicas(&self) -> i32 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.ready_replicas)
+            .unwrap_or(0)
+    }
+
+    fn available_replicas(&self) -> i32 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.available_replicas)
+            .unwrap_or(0)
+    }
+
+    fn updated_replicas(&self) -> i32 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.updated_replicas)
+            .unwrap_or(0)
+    }
+
+    fn pod_template(&self) -> Option<&PodTemplateSpec> {
+        self.spec.as_ref().map(|s| &s.template)
+    }
+
+    // 모든 ordinal이 새 revision으로 올라왔을 때 완료로 판단
+    // partition이 설정된 경우 partition 이상의 ordinal만 갱신 대상
+    fn is_rollout_complete(&self) -> bool {
+        let replicas = self.desired_replicas();
+        if self.observed_generation() != self.generation()
+            || self.ready_replicas() != replicas
+            || self.available_replicas() != replicas
+        {
+            return false;
+        }
+
+        let partition = self.partition();
+        if partition > 0 {
+            return self.updated_replicas() >= replicas - partition;
+        }
+
+        let status = self.status.as_ref();
+        let current_revision = status.and_then(|s| s.current_revision.as_deref());
+        let update_revision = status.and_then(|s| s.update_revision.as_deref());
+
+        self.updated_replicas() == replicas && current_revision == update_revision
+    }
+
+    // OnDelete는 Pod를 지울 때까지 갱신되지 않으므로 spec 반영 여부만 확인
+    // partition이 설정된 경우 partition 이상의 ordinal만 갱신 대상
+    fn is_rollout_in_progress(&self) -> bool {
+        if self.observed_generation() < self.generation() {
+            return true;
+        }
+        let on_delete = self
+            .spec
+            .as_ref()
+            .and_then(|s| s.update_strategy.as_ref())
+            .and_then(|s| s.type_.as_deref())
+            == Some("OnDelete");
+        !on_delete && self.updated_replicas() < self.desired_replicas() - self.partition()
+    }
+
+    fn partition(&self) -> i32 {
+        self.spec
+            .as_ref()
+            .and_then(|s| s.update_strategy.as_ref())
+            .and_then(|s| s.rolling_update.as_ref())
+            .and_then(|r| r.partition)
+            .unwrap_or(0)
+    }
+}
+
+// DaemonSet은 spec에 replicas가 없으므로 스케줄 대상 노드 수를 replicas로 사용
+impl Workload for DaemonSet {
+    fn observed_generation(&self) -> i64 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.observed_generation)
+            .unwrap_or(0)
+    }
+
+    fn desired_replicas(&self) -> i32 {
+        self.status
+            .as_ref()
+            .map(|s| s.desired_number_scheduled)
+            .unwrap_or(0)
+    }
+
+    fn ready_replicas(&self) -> i32 {
+        self.status.as_ref().map(|s| s.number_ready).unwrap_or(0)
+    }
+
+    fn available_replicas(&self) -> i32 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.number_available)
+            .unwrap_or(0)
+    }
+
+    fn updated_replicas(&self) -> i32 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.updated_number_scheduled)
+            .unwrap_or(0)
+    }
+
+    fn pod_template(&self) -> Option<&PodTemplateSpec> {
+        self.spec.as_ref().map(|s| &s.template)
+    }
+
+    // 모든 스케줄 대상 노드가 새 버전으로 갱신되고 사용 가능한 상태
+    fn is_rollout_complete(&self) -> bool {
+        let desired = self.desired_replicas();
+        self.observed_generation() == self.generation()
+            && self.updated_replicas() == desired
+            && self.available_replicas() == desired
+    }
+
+    // OnDelete는 Pod를 지울 때까지 갱신되지 않으므로 spec 반영 여부만 확인
+    fn is_rollout_in_progress(&self) -> bool {
+        if self.observed_generation() < self.generation() {
+            return true;
+        }
+        let on_delete = self
+            .spec
+            .as_ref()
+            .and_then(|s| s.update_strategy.as_ref())
+            .and_then(|s| s.type_.as_deref())
+            == Some("OnDelete");
+        !on_delete && self.updated_replicas() < self.desired_replicas()
+    }
+
+    // replica 수가 노드 수를 따라가므로 스케일 이벤트 대상이 아님
+    fn tracks_replicas(&self) -> bool {
+        false
+    }
+}